@@ -0,0 +1,641 @@
+//! A small GraphQL parser for checking Weaviate queries at compile time.
+//!
+//! The parser only supports the subset of GraphQL used by Weaviate: query operations (optionally
+//! named, with variable definitions) made of fields, arguments and inline fragments. The
+//! top-level fields of each operation must be `Get`, `Aggregate` or `Explore`.
+
+use std::collections::HashSet;
+use std::fmt;
+
+/// An error in the query, with the line and column it was found at.
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid GraphQL at line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/// A variable used as a value in the query.
+#[derive(Debug, PartialEq)]
+pub struct VariableUse {
+    pub name: String,
+    /// The byte range of the variable, including the `$`, in the query.
+    pub start: usize,
+    pub end: usize,
+    /// Whether the variable is declared in the variable definitions of its operation.
+    pub declared: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    Punct(char),
+    Spread,
+    Name(String),
+    Variable(String),
+    Int,
+    Float,
+    String,
+    Eof,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Kind::Punct(c) => write!(f, "`{c}`"),
+            Kind::Spread => write!(f, "`...`"),
+            Kind::Name(name) => write!(f, "`{name}`"),
+            Kind::Variable(name) => write!(f, "`${name}`"),
+            Kind::Int | Kind::Float => write!(f, "a number"),
+            Kind::String => write!(f, "a string"),
+            Kind::Eof => write!(f, "the end of the query"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: Kind,
+    start: usize,
+    end: usize,
+}
+
+/// Check that a query is a valid Weaviate GraphQL query, returning the variables it uses.
+pub fn parse(source: &str) -> Result<Vec<VariableUse>, ParseError> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser {
+        source,
+        tokens,
+        pos: 0,
+        declared: HashSet::new(),
+        variables: Vec::new(),
+    };
+    parser.document()?;
+    Ok(parser.variables)
+}
+
+fn error_at(source: &str, offset: usize, message: impl Into<String>) -> ParseError {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+    ParseError {
+        line,
+        column,
+        message: message.into(),
+    }
+}
+
+fn is_name_start(c: char) -> bool {
+    c == '_' || c.is_ascii_alphabetic()
+}
+
+fn is_name_continue(c: char) -> bool {
+    c == '_' || c.is_ascii_alphanumeric()
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some(&(start, c)) = chars.peek() {
+        match c {
+            c if c.is_whitespace() || c == ',' || c == '\u{feff}' => {
+                chars.next();
+            }
+            '#' => while chars.next_if(|&(_, c)| c != '\n').is_some() {},
+            '!' | '(' | ')' | ':' | '=' | '@' | '[' | ']' | '{' | '}' | '|' | '&' => {
+                chars.next();
+                tokens.push(Token {
+                    kind: Kind::Punct(c),
+                    start,
+                    end: start + 1,
+                });
+            }
+            '.' => {
+                if !source[start..].starts_with("...") {
+                    return Err(error_at(source, start, "unexpected `.`, expected `...`"));
+                }
+                chars.nth(2);
+                tokens.push(Token {
+                    kind: Kind::Spread,
+                    start,
+                    end: start + 3,
+                });
+            }
+            '$' => {
+                chars.next();
+                let name = read_name(&mut chars);
+                if name.is_empty() {
+                    return Err(error_at(
+                        source,
+                        start,
+                        "expected a variable name after `$`",
+                    ));
+                }
+                tokens.push(Token {
+                    end: start + 1 + name.len(),
+                    kind: Kind::Variable(name),
+                    start,
+                });
+            }
+            c if is_name_start(c) => {
+                let name = read_name(&mut chars);
+                tokens.push(Token {
+                    end: start + name.len(),
+                    kind: Kind::Name(name),
+                    start,
+                });
+            }
+            c if c == '-' || c.is_ascii_digit() => {
+                let (kind, end) = read_number(source, start)?;
+                while chars.next_if(|&(i, _)| i < end).is_some() {}
+                tokens.push(Token { kind, start, end });
+            }
+            '"' => {
+                let end = read_string(source, start)?;
+                while chars.next_if(|&(i, _)| i < end).is_some() {}
+                tokens.push(Token {
+                    kind: Kind::String,
+                    start,
+                    end,
+                });
+            }
+            c => {
+                return Err(error_at(
+                    source,
+                    start,
+                    format!("unexpected character `{c}`"),
+                ))
+            }
+        }
+    }
+    tokens.push(Token {
+        kind: Kind::Eof,
+        start: source.len(),
+        end: source.len(),
+    });
+    Ok(tokens)
+}
+
+fn read_name(chars: &mut std::iter::Peekable<std::str::CharIndices>) -> String {
+    let mut name = String::new();
+    if let Some((_, c)) = chars.next_if(|&(_, c)| is_name_start(c)) {
+        name.push(c);
+        while let Some((_, c)) = chars.next_if(|&(_, c)| is_name_continue(c)) {
+            name.push(c);
+        }
+    }
+    name
+}
+
+fn read_number(source: &str, start: usize) -> Result<(Kind, usize), ParseError> {
+    let bytes = source.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+    let mut i = start;
+    if bytes[i] == b'-' {
+        i += 1;
+    }
+    let int_end = digits(i);
+    if int_end == i {
+        return Err(error_at(source, start, "expected a digit after `-`"));
+    }
+    i = int_end;
+    let mut kind = Kind::Int;
+    if i < bytes.len() && bytes[i] == b'.' && !source[i..].starts_with("...") {
+        let end = digits(i + 1);
+        if end == i + 1 {
+            return Err(error_at(source, i, "expected a digit after `.`"));
+        }
+        i = end;
+        kind = Kind::Float;
+    }
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        i += 1;
+        if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+            i += 1;
+        }
+        let end = digits(i);
+        if end == i {
+            return Err(error_at(source, i, "expected a digit in the exponent"));
+        }
+        i = end;
+        kind = Kind::Float;
+    }
+    if i < bytes.len() && (is_name_start(bytes[i] as char) || bytes[i] == b'.') {
+        return Err(error_at(source, i, "invalid number"));
+    }
+    Ok((kind, i))
+}
+
+fn read_string(source: &str, start: usize) -> Result<usize, ParseError> {
+    if source[start..].starts_with("\"\"\"") {
+        let body = start + 3;
+        let mut i = body;
+        while i < source.len() {
+            if source[i..].starts_with("\\\"\"\"") {
+                i += 4;
+            } else if source[i..].starts_with("\"\"\"") {
+                return Ok(i + 3);
+            } else {
+                i += source[i..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        return Err(error_at(source, start, "unterminated block string"));
+    }
+    let mut chars = source[start + 1..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok(start + 1 + i + 1),
+            '\n' | '\r' => break,
+            '\\' => match chars.next() {
+                Some((_, '"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't')) => {}
+                Some((_, 'u')) => {
+                    let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                    if hex.len() != 4 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                        return Err(error_at(
+                            source,
+                            start + 1 + i,
+                            "invalid unicode escape sequence",
+                        ));
+                    }
+                }
+                _ => {
+                    return Err(error_at(source, start + 1 + i, "invalid escape sequence"));
+                }
+            },
+            _ => {}
+        }
+    }
+    Err(error_at(source, start, "unterminated string"))
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+    declared: HashSet<String>,
+    variables: Vec<VariableUse>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if token.kind != Kind::Eof {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn is(&self, kind: &Kind) -> bool {
+        &self.peek().kind == kind
+    }
+
+    fn is_punct(&self, c: char) -> bool {
+        self.is(&Kind::Punct(c))
+    }
+
+    fn unexpected(&self, expected: &str) -> ParseError {
+        let token = self.peek();
+        error_at(
+            self.source,
+            token.start,
+            format!("expected {expected}, found {}", token.kind),
+        )
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<(), ParseError> {
+        if !self.is_punct(c) {
+            return Err(self.unexpected(&format!("`{c}`")));
+        }
+        self.next();
+        Ok(())
+    }
+
+    /// Check if there are more items in a list that is closed with `close`.
+    fn more(&self, close: char) -> Result<bool, ParseError> {
+        if self.is_punct(close) {
+            return Ok(false);
+        }
+        if self.is(&Kind::Eof) {
+            return Err(self.unexpected(&format!("`{close}`")));
+        }
+        Ok(true)
+    }
+
+    fn name(&mut self) -> Result<String, ParseError> {
+        match self.peek().kind.clone() {
+            Kind::Name(name) => {
+                self.next();
+                Ok(name)
+            }
+            _ => Err(self.unexpected("a name")),
+        }
+    }
+
+    fn document(&mut self) -> Result<(), ParseError> {
+        let mut operations = 0;
+        let mut anonymous = false;
+        let mut names = HashSet::new();
+        while !self.is(&Kind::Eof) {
+            let start = self.peek().start;
+            let name = self.operation()?;
+            operations += 1;
+            match name {
+                Some(name) if !names.insert(name.clone()) => {
+                    return Err(error_at(
+                        self.source,
+                        start,
+                        format!("duplicate operation `{name}`"),
+                    ));
+                }
+                Some(_) => {}
+                None => anonymous = true,
+            }
+            if anonymous && operations > 1 {
+                return Err(error_at(
+                    self.source,
+                    start,
+                    "an anonymous operation must be the only operation in the query",
+                ));
+            }
+        }
+        if operations == 0 {
+            return Err(self.unexpected("a query"));
+        }
+        Ok(())
+    }
+
+    fn operation(&mut self) -> Result<Option<String>, ParseError> {
+        self.declared.clear();
+        let mut name = None;
+        match self.peek().kind.clone() {
+            Kind::Punct('{') => {}
+            Kind::Name(keyword) if keyword == "query" => {
+                self.next();
+                if let Kind::Name(_) = self.peek().kind {
+                    name = Some(self.name()?);
+                }
+                if self.is_punct('(') {
+                    self.variable_definitions()?;
+                }
+            }
+            Kind::Name(keyword) if keyword == "mutation" || keyword == "subscription" => {
+                return Err(error_at(
+                    self.source,
+                    self.peek().start,
+                    format!("Weaviate does not support `{keyword}` operations"),
+                ));
+            }
+            Kind::Name(keyword) if keyword == "fragment" => {
+                return Err(error_at(
+                    self.source,
+                    self.peek().start,
+                    "named fragments are not supported, use inline fragments instead",
+                ));
+            }
+            _ => return Err(self.unexpected("`{` or `query`")),
+        }
+
+        self.expect_punct('{')?;
+        loop {
+            let token = self.peek().clone();
+            let field = self.field()?;
+            if !matches!(field.as_str(), "Get" | "Aggregate" | "Explore") {
+                return Err(error_at(
+                    self.source,
+                    token.start,
+                    format!("expected `Get`, `Aggregate` or `Explore`, found `{field}`"),
+                ));
+            }
+            if !self.more('}')? {
+                break;
+            }
+        }
+        self.expect_punct('}')?;
+        Ok(name)
+    }
+
+    fn variable_definitions(&mut self) -> Result<(), ParseError> {
+        self.expect_punct('(')?;
+        loop {
+            let token = self.next();
+            let Kind::Variable(name) = token.kind else {
+                self.pos -= 1;
+                return Err(self.unexpected("a variable definition"));
+            };
+            if !self.declared.insert(name.clone()) {
+                return Err(error_at(
+                    self.source,
+                    token.start,
+                    format!("duplicate variable `${name}`"),
+                ));
+            }
+            self.expect_punct(':')?;
+            self.type_reference()?;
+            if self.is_punct('=') {
+                self.next();
+                self.value(true)?;
+            }
+            if !self.more(')')? {
+                break;
+            }
+        }
+        self.expect_punct(')')
+    }
+
+    fn type_reference(&mut self) -> Result<(), ParseError> {
+        if self.is_punct('[') {
+            self.next();
+            self.type_reference()?;
+            self.expect_punct(']')?;
+        } else {
+            self.name()?;
+        }
+        if self.is_punct('!') {
+            self.next();
+        }
+        Ok(())
+    }
+
+    fn selection_set(&mut self) -> Result<(), ParseError> {
+        self.expect_punct('{')?;
+        loop {
+            if self.is(&Kind::Spread) {
+                self.next();
+                match self.peek().kind.clone() {
+                    Kind::Name(on) if on == "on" => {
+                        self.next();
+                        self.name()?;
+                        self.selection_set()?;
+                    }
+                    _ => return Err(self.unexpected("`on`")),
+                }
+            } else {
+                self.field()?;
+            }
+            if !self.more('}')? {
+                break;
+            }
+        }
+        self.expect_punct('}')
+    }
+
+    /// Parse a field, returning its name (not its alias).
+    fn field(&mut self) -> Result<String, ParseError> {
+        let mut name = self.name()?;
+        if self.is_punct(':') {
+            self.next();
+            name = self.name()?;
+        }
+        if self.is_punct('(') {
+            self.arguments()?;
+        }
+        if self.is_punct('{') {
+            self.selection_set()?;
+        }
+        Ok(name)
+    }
+
+    fn arguments(&mut self) -> Result<(), ParseError> {
+        self.expect_punct('(')?;
+        let mut names = HashSet::new();
+        loop {
+            let start = self.peek().start;
+            let name = self.name()?;
+            if !names.insert(name.clone()) {
+                return Err(error_at(
+                    self.source,
+                    start,
+                    format!("duplicate argument `{name}`"),
+                ));
+            }
+            self.expect_punct(':')?;
+            self.value(false)?;
+            if !self.more(')')? {
+                break;
+            }
+        }
+        self.expect_punct(')')
+    }
+
+    fn value(&mut self, constant: bool) -> Result<(), ParseError> {
+        let token = self.next();
+        match token.kind {
+            Kind::Variable(name) if !constant => {
+                self.variables.push(VariableUse {
+                    declared: self.declared.contains(&name),
+                    name,
+                    start: token.start,
+                    end: token.end,
+                });
+            }
+            Kind::Int | Kind::Float | Kind::String | Kind::Name(_) => {}
+            Kind::Punct('[') => {
+                while self.more(']')? {
+                    self.value(constant)?;
+                }
+                self.next();
+            }
+            Kind::Punct('{') => {
+                let mut names = HashSet::new();
+                while self.more('}')? {
+                    let start = self.peek().start;
+                    let name = self.name()?;
+                    if !names.insert(name.clone()) {
+                        return Err(error_at(
+                            self.source,
+                            start,
+                            format!("duplicate field `{name}`"),
+                        ));
+                    }
+                    self.expect_punct(':')?;
+                    self.value(constant)?;
+                }
+                self.next();
+            }
+            _ => {
+                self.pos -= 1;
+                return Err(self.unexpected("a value"));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(source: &str) -> String {
+        parse(source).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_parse_valid() {
+        let source = r#"
+            # The top questions
+            {
+              Get {
+                JeopardyQuestion(
+                  limit: 2
+                  where: {path: ["points"], operator: GreaterThan, valueInt: -1}
+                  nearText: {concepts: ["animals \"in\" movies"], distance: 0.5e1}
+                ) {
+                  question
+                  hasCategory { ... on JeopardyCategory { title } }
+                  _additional { id distance }
+                }
+              }
+              Aggregate { Article { meta { count } } }
+            }
+        "#;
+        assert_eq!(parse(source), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_parse_variables() {
+        let source = "query Q($limit: Int! = 1, $ids: [String]) {
+            Get { Article(limit: $limit, where: {valueText: $text}) { title } }
+        }";
+        let variables = parse(source).unwrap();
+        assert_eq!(variables.len(), 2);
+        assert!(variables[0].declared);
+        assert_eq!(variables[1].name, "text");
+        assert!(!variables[1].declared);
+        assert_eq!(&source[variables[1].start..variables[1].end], "$text");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            error("{ Get { Article { title } }"),
+            "invalid GraphQL at line 1, column 28: expected `}`, found the end of the query"
+        );
+        assert_eq!(
+            error("{\n  Get { Article(limit 1) { title } }\n}"),
+            "invalid GraphQL at line 2, column 23: expected `:`, found a number"
+        );
+        assert!(error("{ Objects { Article { title } } }").contains("expected `Get`"));
+        assert!(error(r#"{ Get { Article(where: "a\q") { title } } }"#).contains("escape"));
+        assert!(error("mutation { Get { A { b } } }").contains("mutation"));
+        assert!(error("{ Get { A(limit: 1, limit: 2) { b } } }").contains("duplicate"));
+        assert!(error("").contains("expected a query"));
+    }
+}
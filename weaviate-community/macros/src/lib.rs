@@ -0,0 +1,179 @@
+//! # weaviate-community-macros
+//!
+//! Procedural macros for the `weaviate-community` crate. These are re-exported by
+//! `weaviate-community` when its `macros` feature is enabled, and should be used from there.
+use std::collections::HashMap;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Expr, Ident, LitStr, Token};
+
+mod graphql;
+
+/// The input of the `weaviate_query!` macro: the query, followed by the values to interpolate.
+struct QueryInput {
+    query: LitStr,
+    args: Vec<QueryArg>,
+}
+
+/// A value to interpolate into the query, either `name = expr` or just `name`.
+struct QueryArg {
+    name: Ident,
+    value: Expr,
+}
+
+impl Parse for QueryInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let query = input.parse()?;
+        let mut args = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            args = Punctuated::<QueryArg, Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect();
+        }
+        Ok(QueryInput { query, args })
+    }
+}
+
+impl Parse for QueryArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        let value = if input.parse::<Option<Token![=]>>()?.is_some() {
+            input.parse()?
+        } else {
+            syn::parse_quote!(#name)
+        };
+        Ok(QueryArg { name, value })
+    }
+}
+
+/// Build a `RawQuery` from a Weaviate GraphQL query that is checked at compile time.
+///
+/// The query must be a valid GraphQL query whose top-level fields are `Get`, `Aggregate` or
+/// `Explore`. Syntax errors are reported as compile errors, with the line and column of the
+/// error in the query.
+///
+/// Rust values are interpolated into the query with `$name` in a value position, and passing the
+/// value as `name = expr` (or just `name` for a variable of the same name) after the query. Each
+/// value is rendered with `ToGraphQL`, which quotes and escapes strings, so they can not change
+/// the structure of the query. Variables that are not passed to the macro are left as GraphQL
+/// variables, and must be declared by the operation and set with `RawQuery::with_variable`.
+///
+/// # Example
+/// ```
+/// use weaviate_community::models::query::RawQuery;
+/// use weaviate_community_macros::weaviate_query;
+///
+/// let concept = "animals in \"movies\"";
+/// let query: RawQuery = weaviate_query!(
+///     r#"{
+///       Get {
+///         JeopardyQuestion(limit: $limit, nearText: {concepts: [$concept]}) {
+///           question
+///           answer
+///         }
+///       }
+///     }"#,
+///     limit = 2,
+///     concept,
+/// );
+///
+/// assert!(query.query.contains(r#"limit: 2, nearText: {concepts: ["animals in \"movies\""]}"#));
+/// ```
+///
+/// Invalid queries do not compile:
+/// ```compile_fail
+/// use weaviate_community_macros::weaviate_query;
+///
+/// let query = weaviate_query!("{ Get { JeopardyQuestion(limit 2) { question } } }");
+/// ```
+#[proc_macro]
+pub fn weaviate_query(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as QueryInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: QueryInput) -> syn::Result<proc_macro2::TokenStream> {
+    let source = input.query.value();
+    let variables = graphql::parse(&source)
+        .map_err(|err| syn::Error::new(input.query.span(), err.to_string()))?;
+
+    let mut args: HashMap<String, usize> = HashMap::new();
+    for (i, arg) in input.args.iter().enumerate() {
+        if args.insert(arg.name.to_string(), i).is_some() {
+            return Err(syn::Error::new(
+                arg.name.span(),
+                format!("`{}` is passed more than once", arg.name),
+            ));
+        }
+    }
+
+    let mut used = vec![false; input.args.len()];
+    let mut statements = Vec::new();
+    let mut last = 0;
+    for variable in &variables {
+        let Some(&i) = args.get(&variable.name) else {
+            if variable.declared {
+                continue;
+            }
+            return Err(syn::Error::new(
+                input.query.span(),
+                format!(
+                    "`${0}` is not declared by the query, pass it as `{0} = ...` to interpolate it",
+                    variable.name
+                ),
+            ));
+        };
+        if variable.declared {
+            return Err(syn::Error::new(
+                input.args[i].name.span(),
+                format!(
+                    "`${}` is declared as a GraphQL variable, and can not also be interpolated",
+                    variable.name
+                ),
+            ));
+        }
+        used[i] = true;
+        let text = &source[last..variable.start];
+        let arg = format_ident!("__weaviate_arg_{}", i, span = Span::mixed_site());
+        statements.push(quote! {
+            query.push_str(#text);
+            query.push_str(&::weaviate_community::models::query::ToGraphQL::to_graphql(#arg));
+        });
+        last = variable.end;
+    }
+    if let Some(i) = used.iter().position(|used| !used) {
+        return Err(syn::Error::new(
+            input.args[i].name.span(),
+            format!("`${}` is not used in the query", input.args[i].name),
+        ));
+    }
+
+    if statements.is_empty() {
+        return Ok(quote! {
+            ::weaviate_community::models::query::RawQuery::new(#source)
+        });
+    }
+
+    let text = &source[last..];
+    let bindings = input.args.iter().enumerate().map(|(i, arg)| {
+        let ident = format_ident!("__weaviate_arg_{}", i, span = Span::mixed_site());
+        let value = &arg.value;
+        quote! { let #ident = &(#value); }
+    });
+    Ok(quote! {
+        {
+            #(#bindings)*
+            let mut query = ::std::string::String::new();
+            #(#statements)*
+            query.push_str(#text);
+            ::weaviate_community::models::query::RawQuery::new(&query)
+        }
+    })
+}
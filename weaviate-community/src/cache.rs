@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// An in-memory cache of GraphQL query results.
+///
+/// The cache is opt-in, and is enabled with `WeaviateClientBuilder::with_query_cache`. Once
+/// enabled, the results of `Query::get`, `Query::aggregate`, `Query::multi` and `Query::raw`
+/// (and the methods built on them) are keyed by the rendered query payload, which includes the
+/// tenant, variables and operation name of the query. The headers of a client are fixed when it
+/// is built, so each client (and its clones) has its own cache.
+///
+/// Entries expire after the configured time to live. When the cache is full, the least recently
+/// used entry is evicted. Only successful responses are cached.
+///
+/// Entries for a class are invalidated automatically when the client mutates that class through
+/// the `Objects`, `Batch` or `Schema` endpoints. Changes made by other clients are not seen until
+/// the entries expire, or are invalidated manually with `QueryCache::invalidate_class` or
+/// `QueryCache::clear`.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use weaviate_community::WeaviateClient;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = WeaviateClient::builder("http://localhost:8080")
+///     .with_query_cache(1000, Duration::from_secs(60))
+///     .build()?;
+///
+/// if let Some(cache) = client.query_cache() {
+///     cache.invalidate_class("JeopardyQuestion");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct QueryCache {
+    max_entries: usize,
+    ttl: Duration,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    value: serde_json::Value,
+    classes: Option<Vec<String>>,
+    inserted: Instant,
+    last_used: u64,
+}
+
+impl CacheEntry {
+    /// Check if the entry holds the result of a query on the given class.
+    ///
+    /// Entries for which the classes are unknown, such as raw queries, depend on every class.
+    fn depends_on(&self, class_name: &str) -> bool {
+        match &self.classes {
+            Some(classes) => classes.iter().any(|class| same_class(class, class_name)),
+            None => true,
+        }
+    }
+}
+
+impl QueryCache {
+    /// Create a new QueryCache holding at most `max_entries` results, each for at most `ttl`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use weaviate_community::QueryCache;
+    ///
+    /// let cache = QueryCache::new(1000, Duration::from_secs(60));
+    /// ```
+    pub fn new(max_entries: usize, ttl: Duration) -> Self {
+        QueryCache {
+            max_entries,
+            ttl,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Remove all of the entries that depend on the given class.
+    ///
+    /// Entries of raw queries are always removed, as the classes they query are not known.
+    pub fn invalidate_class(&self, class_name: &str) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .entries
+            .retain(|_, entry| !entry.depends_on(class_name));
+    }
+
+    /// Remove all of the entries from the cache.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().entries.clear();
+    }
+
+    /// Get the number of entries in the cache, including any that have expired but have not
+    /// been evicted yet.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Check if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the cached response for a query payload, if it is present and has not expired.
+    pub(crate) fn get(&self, key: &str) -> Option<serde_json::Value> {
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+        let expired = match state.entries.get_mut(key) {
+            Some(entry) if entry.inserted.elapsed() < self.ttl => {
+                entry.last_used = clock;
+                return Some(entry.value.clone());
+            }
+            Some(_) => true,
+            None => false,
+        };
+        if expired {
+            state.entries.remove(key);
+        }
+        None
+    }
+
+    /// Cache the response for a query payload.
+    ///
+    /// `classes` are the classes the query depends on, or `None` if they are not known.
+    pub(crate) fn insert(
+        &self,
+        key: String,
+        value: serde_json::Value,
+        classes: Option<Vec<String>>,
+    ) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let clock = state.clock;
+        if !state.entries.contains_key(&key) && state.entries.len() >= self.max_entries {
+            let ttl = self.ttl;
+            state
+                .entries
+                .retain(|_, entry| entry.inserted.elapsed() < ttl);
+            if state.entries.len() >= self.max_entries {
+                let lru = state
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone());
+                if let Some(lru) = lru {
+                    state.entries.remove(&lru);
+                }
+            }
+        }
+        state.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                classes,
+                inserted: Instant::now(),
+                last_used: clock,
+            },
+        );
+    }
+}
+
+/// Compare two class names the way Weaviate does, which capitalizes the first letter of every
+/// class name.
+fn same_class(a: &str, b: &str) -> bool {
+    let mut a = a.chars();
+    let mut b = b.chars();
+    match (a.next(), b.next()) {
+        (Some(x), Some(y)) => x.eq_ignore_ascii_case(&y) && a.eq(b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classes(names: &[&str]) -> Option<Vec<String>> {
+        Some(names.iter().map(|name| name.to_string()).collect())
+    }
+
+    #[test]
+    fn test_query_cache_get_insert() {
+        let cache = QueryCache::new(10, Duration::from_secs(60));
+        assert!(cache.get("a").is_none());
+        cache.insert("a".into(), serde_json::json!(1), classes(&["Article"]));
+        assert_eq!(cache.get("a"), Some(serde_json::json!(1)));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_query_cache_ttl() {
+        let cache = QueryCache::new(10, Duration::ZERO);
+        cache.insert("a".into(), serde_json::json!(1), classes(&["Article"]));
+        assert!(cache.get("a").is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_query_cache_evicts_least_recently_used() {
+        let cache = QueryCache::new(2, Duration::from_secs(60));
+        cache.insert("a".into(), serde_json::json!(1), None);
+        cache.insert("b".into(), serde_json::json!(2), None);
+        assert!(cache.get("a").is_some());
+        cache.insert("c".into(), serde_json::json!(3), None);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn test_query_cache_invalidate_class() {
+        let cache = QueryCache::new(10, Duration::from_secs(60));
+        cache.insert("a".into(), serde_json::json!(1), classes(&["Article"]));
+        cache.insert("b".into(), serde_json::json!(2), classes(&["Author"]));
+        cache.insert("raw".into(), serde_json::json!(3), None);
+        cache.invalidate_class("article");
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+        assert!(cache.get("raw").is_none());
+    }
+}
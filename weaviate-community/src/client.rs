@@ -1,17 +1,26 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use reqwest::header::{HeaderMap, AUTHORIZATION};
 use reqwest::{IntoUrl, Url};
+use tokio::sync::OnceCell;
 
+use crate::cache::QueryCache;
 use crate::endpoints::{
     Backups, Batch, Classification, Meta, Modules, Nodes, Objects, Oidc, Query, Schema,
 };
 use crate::error::WeaviateError;
+use crate::health::{self, Availability, ReadyWaitOptions};
 use crate::models::auth::{ApiKey, AuthSecret};
+use crate::models::meta::{Capability, Metadata};
 
 /// An asynchronous `WeaviateClient` to interact with a Weaviate database.
 #[derive(Debug, Clone)]
 pub struct WeaviateClient {
     pub base_url: Url,
     client: reqwest::Client,
+    query_cache: Option<Arc<QueryCache>>,
+    metadata: Arc<OnceCell<Metadata>>,
 }
 
 impl WeaviateClient {
@@ -85,7 +94,12 @@ impl WeaviateClient {
         // Each of the endpoint categories hold a strong ref to the main client.
         let client = client_builder.build()?;
 
-        Ok(WeaviateClient { base_url, client })
+        Ok(WeaviateClient {
+            base_url,
+            client,
+            query_cache: None,
+            metadata: Arc::new(OnceCell::new()),
+        })
     }
 
     /// Determine if the application is ready to receive traffic.
@@ -153,6 +167,109 @@ impl WeaviateClient {
         }
     }
 
+    /// Wait until the application is live, see `is_live`.
+    ///
+    /// Errors while checking, such as the connection being refused while the server starts, are
+    /// retried until the timeout of the options.
+    ///
+    /// # Errors
+    /// `WeaviateError::WaitTimeout` when the application is not live in time, with the last
+    /// reason it was not.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::{ReadyWaitOptions, WeaviateClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     client.wait_until_live(&ReadyWaitOptions::new()).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_until_live(&self, options: &ReadyWaitOptions) -> Result<(), WeaviateError> {
+        health::wait_until(self, Availability::Live, options).await
+    }
+
+    /// Wait until the application is ready, see `is_ready`.
+    ///
+    /// With `ReadyWaitOptions::with_cluster_health`, this also waits for all nodes to report
+    /// `HEALTHY` and all shards to report `READY`. Errors while checking, such as the connection
+    /// being refused while the server starts, are retried until the timeout of the options.
+    ///
+    /// # Errors
+    /// `WeaviateError::WaitTimeout` when the application is not ready in time, with the last
+    /// reason it was not.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use weaviate_community::{ReadyWaitOptions, WeaviateClient};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let options = ReadyWaitOptions::new()
+    ///         .with_timeout(Duration::from_secs(120))
+    ///         .with_cluster_health();
+    ///     client.wait_until_ready(&options).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_until_ready(&self, options: &ReadyWaitOptions) -> Result<(), WeaviateError> {
+        health::wait_until(self, Availability::Ready, options).await
+    }
+
+    /// Get the metadata of the server, which is requested once and then cached by the client and
+    /// its clones.
+    ///
+    /// Use `meta().get_meta()` to always request the latest metadata.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let metadata = client.server_metadata().await?;
+    ///     if metadata.version.at_least(1, 24, 0) {
+    ///         println!("running {}", metadata.version);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn server_metadata(&self) -> Result<&Metadata, WeaviateError> {
+        self.metadata
+            .get_or_try_init(|| async { self.meta().get_meta().await })
+            .await
+    }
+
+    /// Check that the server supports a capability, using the cached `server_metadata`.
+    ///
+    /// This can be used to fail early with a clear error before making a request that the server
+    /// would reject.
+    ///
+    /// # Errors
+    /// `WeaviateError::Unsupported` if the server does not support the capability.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::meta::Capability;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     client.require(&Capability::NamedVectors).await?;
+    ///     client.require(&Capability::Module("text2vec-openai".into())).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn require(&self, capability: &Capability) -> Result<(), WeaviateError> {
+        self.server_metadata().await?.require(capability)
+    }
+
     pub fn schema(&self) -> Schema<'_> {
         Schema::new(self)
     }
@@ -193,6 +310,34 @@ impl WeaviateClient {
         Modules::new(self)
     }
 
+    /// Get the query cache of the client, if it was enabled with
+    /// `WeaviateClientBuilder::with_query_cache`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = WeaviateClient::builder("http://localhost:8080")
+    ///     .with_query_cache(1000, Duration::from_secs(60))
+    ///     .build()?;
+    /// client.query_cache().unwrap().clear();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query_cache(&self) -> Option<&QueryCache> {
+        self.query_cache.as_deref()
+    }
+
+    /// Invalidate the cached query results that depend on a class, after the client has mutated
+    /// it.
+    pub(crate) fn invalidate_class(&self, class_name: &str) {
+        if let Some(cache) = &self.query_cache {
+            cache.invalidate_class(class_name);
+        }
+    }
+
     pub(crate) fn get(&self, url: impl IntoUrl) -> reqwest::RequestBuilder {
         self.client.get(url)
     }
@@ -248,6 +393,7 @@ pub struct WeaviateClientBuilder {
     pub base_url: String,
     pub auth_secret: Option<AuthSecret>,
     pub api_keys: Vec<ApiKey>,
+    pub query_cache: Option<QueryCache>,
 }
 
 impl WeaviateClientBuilder {
@@ -278,6 +424,7 @@ impl WeaviateClientBuilder {
             base_url: base_url.into(),
             auth_secret: None,
             api_keys: Vec::new(),
+            query_cache: None,
         }
     }
 
@@ -321,6 +468,28 @@ impl WeaviateClientBuilder {
         self
     }
 
+    /// Enables caching of GraphQL query results in the client.
+    ///
+    /// See `QueryCache` for what is cached and when entries are invalidated.
+    ///
+    /// # Parameters
+    /// - max_entries: the maximum number of query results to hold
+    /// - ttl: how long a query result is valid for
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClientBuilder;
+    ///
+    /// let client = WeaviateClientBuilder::new("http://localhost:8080")
+    ///     .with_query_cache(1000, Duration::from_secs(60))
+    ///     .build();
+    /// ```
+    pub fn with_query_cache(mut self, max_entries: usize, ttl: Duration) -> WeaviateClientBuilder {
+        self.query_cache = Some(QueryCache::new(max_entries, ttl));
+        self
+    }
+
     /// Build a `WeaviateClient` from the values set in the WeaviateClientBuilder.
     ///
     /// # Example
@@ -330,7 +499,9 @@ impl WeaviateClientBuilder {
     /// let client = WeaviateClientBuilder::new("http://localhost:8080").build();
     /// ```
     pub fn build(self) -> Result<WeaviateClient, WeaviateError> {
-        let client = WeaviateClient::new(&self.base_url, self.auth_secret, Some(self.api_keys))?;
+        let mut client =
+            WeaviateClient::new(&self.base_url, self.auth_secret, Some(self.api_keys))?;
+        client.query_cache = self.query_cache.map(Arc::new);
         Ok(client)
     }
 }
@@ -398,4 +569,129 @@ mod tests {
         assert!(res.is_ok());
         assert!(!res.unwrap());
     }
+
+    fn test_wait_options() -> ReadyWaitOptions {
+        ReadyWaitOptions::new()
+            .with_timeout(Duration::from_millis(100))
+            .with_backoff(Duration::from_millis(10), Duration::from_millis(20))
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_live_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/.well-known/live", 200, "").await;
+        let res = client.wait_until_live(&test_wait_options()).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_ready_timeout() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("GET", "/v1/.well-known/ready")
+            .with_status(503)
+            .expect_at_least(2)
+            .create();
+        let res = client.wait_until_ready(&test_wait_options()).await;
+        mock.assert();
+        assert!(matches!(
+            res,
+            Err(WeaviateError::WaitTimeout { reason, .. }) if reason == "server is not ready"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_ready_no_response() {
+        // A server that accepts connections but never responds
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let _server = tokio::spawn(async move {
+            let mut connections = Vec::new();
+            while let Ok((connection, _)) = listener.accept().await {
+                connections.push(connection);
+            }
+        });
+        let client = WeaviateClient::builder(format!("http://{addr}"))
+            .build()
+            .unwrap();
+        let res = tokio::time::timeout(
+            Duration::from_secs(5),
+            client.wait_until_ready(&test_wait_options()),
+        )
+        .await
+        .expect("wait_until_ready did not return after its timeout");
+        assert!(matches!(
+            res,
+            Err(WeaviateError::WaitTimeout { reason, .. })
+                if reason == "server did not respond in time"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_ready_cluster_health() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let _ready = mock_get(&mut mock_server, "/v1/.well-known/ready", 200, "").await;
+        let _nodes = mock_get(
+            &mut mock_server,
+            "/v1/nodes/",
+            200,
+            r#"{"nodes": [{"name": "weaviate-0", "status": "HEALTHY"}]}"#,
+        )
+        .await;
+        let _schema = mock_get(
+            &mut mock_server,
+            "/v1/schema/",
+            200,
+            r#"{"classes": [{"class": "Article"}]}"#,
+        )
+        .await;
+        let shards = mock_get(
+            &mut mock_server,
+            "/v1/schema/Article/shards",
+            200,
+            r#"[{"name": "abc", "status": "LOADING"}]"#,
+        )
+        .await;
+
+        let options = test_wait_options().with_cluster_health();
+        let res = client.wait_until_ready(&options).await;
+        assert!(matches!(
+            res,
+            Err(WeaviateError::WaitTimeout { reason, .. })
+                if reason == "shard `abc` of class `Article` is LOADING"
+        ));
+
+        shards.remove();
+        let _shards = mock_get(
+            &mut mock_server,
+            "/v1/schema/Article/shards",
+            200,
+            r#"[{"name": "abc", "status": "READY"}]"#,
+        )
+        .await;
+        assert!(client.wait_until_ready(&options).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_require_caches_metadata() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("GET", "/v1/meta/")
+            .with_status(200)
+            .with_body(r#"{"hostname": "http://[::]:8080", "modules": {}, "version": "1.23.0"}"#)
+            .expect(1)
+            .create();
+        assert!(client
+            .require(&Capability::Module("text2vec-openai".into()))
+            .await
+            .is_err());
+        let res = client.clone().require(&Capability::NamedVectors).await;
+        mock.assert();
+        assert!(matches!(res, Err(WeaviateError::Unsupported { .. })));
+        assert!(client
+            .require(&Capability::Module("x".into()))
+            .await
+            .is_err());
+    }
 }
@@ -1,9 +1,11 @@
+use std::time::Instant;
+
 use reqwest::{StatusCode, Url};
 
 use crate::error::WeaviateError;
 use crate::models::backups::{
-    BackupBackends, BackupCreateRequest, BackupResponse, BackupRestoreRequest, BackupStatus,
-    BackupStatusResponse,
+    BackupBackends, BackupCreateRequest, BackupListItem, BackupResponse, BackupRestoreRequest,
+    BackupStatus, BackupStatusResponse, BackupWaitOptions,
 };
 use crate::{ResponseExt, WeaviateClient};
 
@@ -29,26 +31,45 @@ impl<'a> Backups<'a> {
         self.client.base_url.join("v1/backups/")
     }
 
+    /// Get the endpoint for the given path segments below the backups endpoint, such as the
+    /// backend and the id of a backup.
+    ///
+    /// Each segment is percent-encoded, so the name of a custom backend can not change the path.
+    fn segments_endpoint(&self, segments: &[&str]) -> Result<Url, WeaviateError> {
+        let mut endpoint = self.endpoint()?;
+        endpoint
+            .path_segments_mut()
+            .map_err(|_| WeaviateError::Other("the base URL can not have a path".into()))?
+            .pop_if_empty()
+            .extend(segments);
+        Ok(endpoint)
+    }
+
     /// Create a new backup
     ///
+    /// When `wait` is `Some`, this waits for the backup to complete with the given options, see
+    /// `Backups::wait_for_completion`. Otherwise it returns as soon as the backup has started.
+    ///
     /// # Examples
-    /// Creating a backup to the filesystem, waiting for completion
+    /// Creating a backup to the filesystem, waiting at most 10 minutes for completion
     /// ```no_run
+    /// use std::time::Duration;
     /// use weaviate_community::WeaviateClient;
-    /// use weaviate_community::models::backups::{BackupBackends, BackupCreateRequest};
+    /// use weaviate_community::models::backups::{
+    ///     BackupBackends,
+    ///     BackupCreateRequest,
+    ///     BackupWaitOptions
+    /// };
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
-    ///     let my_request = BackupCreateRequest {
-    ///         id: "doc-test-backup".into(),
-    ///         include: None,
-    ///         exclude: None
-    ///     };
+    ///     let my_request = BackupCreateRequest::builder("doc-test-backup").build();
+    ///     let options = BackupWaitOptions::new().with_timeout(Duration::from_secs(600));
     ///     let res = client.backups().create(
     ///         BackupBackends::FILESYSTEM,
     ///         &my_request,
-    ///         true
+    ///         Some(&options)
     ///     ).await?;
     ///     println!("{:#?}", res);
     ///     Ok(())
@@ -58,9 +79,9 @@ impl<'a> Backups<'a> {
         &self,
         backend: BackupBackends,
         backup_request: &BackupCreateRequest,
-        wait_for_completion: bool,
+        wait: Option<&BackupWaitOptions>,
     ) -> Result<BackupResponse, WeaviateError> {
-        let endpoint = self.endpoint()?.join(backend.value())?;
+        let endpoint = self.segments_endpoint(&[backend.value()])?;
         let payload = serde_json::to_value(backup_request)?;
         let mut res: BackupResponse = self
             .client
@@ -73,11 +94,11 @@ impl<'a> Backups<'a> {
             .json()
             .await?;
 
-        if wait_for_completion {
+        if let Some(options) = wait {
             let complete = self
-                .wait_for_completion(backend, &backup_request.id, false)
+                .wait_for_completion(backend, &backup_request.id, false, options)
                 .await?;
-            res.status = complete;
+            res.status = complete.status;
         }
         Ok(res)
     }
@@ -107,11 +128,11 @@ impl<'a> Backups<'a> {
         backup_id: &str,
         restore: bool,
     ) -> Result<BackupStatusResponse, WeaviateError> {
-        let mut path: String = format!("{}/{}", backend.value(), backup_id);
+        let mut segments = vec![backend.value(), backup_id];
         if restore {
-            path.push_str("/restore");
+            segments.push("restore");
         }
-        let endpoint = self.endpoint()?.join(&path)?;
+        let endpoint = self.segments_endpoint(&segments)?;
         let res: BackupStatusResponse = self
             .client
             .get(endpoint)
@@ -126,24 +147,28 @@ impl<'a> Backups<'a> {
 
     /// Restore a backup
     ///
+    /// When `wait` is `Some`, this waits for the restore to complete with the given options, see
+    /// `Backups::wait_for_completion`. Otherwise it returns as soon as the restore has started.
+    ///
     /// # Examples
     /// Restore a backup from the filesystem, waiting for completion
     /// ```no_run
     /// use weaviate_community::WeaviateClient;
-    /// use weaviate_community::models::backups::{BackupBackends, BackupRestoreRequest};
+    /// use weaviate_community::models::backups::{
+    ///     BackupBackends,
+    ///     BackupRestoreRequest,
+    ///     BackupWaitOptions
+    /// };
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
-    ///     let my_request = BackupRestoreRequest {
-    ///         include: None,
-    ///         exclude: None
-    ///     };
+    ///     let my_request = BackupRestoreRequest::builder().build();
     ///     let res = client.backups().restore(
     ///         BackupBackends::FILESYSTEM,
     ///         "doc-test-backup",
     ///         &my_request,
-    ///         true
+    ///         Some(&BackupWaitOptions::new())
     ///     ).await?;
     ///     println!("{:#?}", res);
     ///     Ok(())
@@ -154,10 +179,9 @@ impl<'a> Backups<'a> {
         backend: BackupBackends,
         backup_id: &str,
         backup_request: &BackupRestoreRequest,
-        wait_for_completion: bool,
+        wait: Option<&BackupWaitOptions>,
     ) -> Result<BackupResponse, WeaviateError> {
-        let path = format!("{}/{}/restore", backend.value(), backup_id);
-        let endpoint = self.endpoint()?.join(&path)?;
+        let endpoint = self.segments_endpoint(&[backend.value(), backup_id, "restore"])?;
         let payload = serde_json::to_value(backup_request)?;
         let mut res: BackupResponse = self
             .client
@@ -169,37 +193,183 @@ impl<'a> Backups<'a> {
             .await?
             .json()
             .await?;
-        if wait_for_completion {
-            let complete = self.wait_for_completion(backend, backup_id, true).await?;
-            res.status = complete;
+        if let Some(options) = wait {
+            let complete = self
+                .wait_for_completion(backend, backup_id, true, options)
+                .await?;
+            res.status = complete.status;
         }
         Ok(res)
     }
 
-    /// Wait for a backup to complete before returning
-    async fn wait_for_completion(
+    /// List all of the backups on a backend.
+    ///
+    /// GET /v1/backups/{backend}
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::backups::BackupBackends;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     for backup in client.backups().list(BackupBackends::FILESYSTEM).await? {
+    ///         println!("{} {:?}", backup.id, backup.status);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn list(
+        &self,
+        backend: BackupBackends,
+    ) -> Result<Vec<BackupListItem>, WeaviateError> {
+        let endpoint = self.segments_endpoint(&[backend.value()])?;
+        let res: Vec<BackupListItem> = self
+            .client
+            .get(endpoint)
+            .send()
+            .await?
+            .check_status(StatusCode::OK)
+            .await?
+            .json()
+            .await?;
+        Ok(res)
+    }
+
+    /// Cancel a backup that is in progress.
+    ///
+    /// The status of a cancelled backup becomes `BackupStatus::CANCELED`. Backups that have
+    /// already completed can not be cancelled.
+    ///
+    /// DELETE /v1/backups/{backend}/{backup_id}
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::backups::BackupBackends;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     client.backups().cancel(BackupBackends::FILESYSTEM, "doc-test-backup").await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn cancel(
+        &self,
+        backend: BackupBackends,
+        backup_id: &str,
+    ) -> Result<bool, WeaviateError> {
+        let endpoint = self.segments_endpoint(&[backend.value(), backup_id])?;
+        let _res = self
+            .client
+            .delete(endpoint)
+            .send()
+            .await?
+            .check_status(StatusCode::NO_CONTENT)
+            .await?;
+        Ok(true)
+    }
+
+    /// Wait for a backup or restore to complete, polling its status.
+    ///
+    /// The status is requested every `poll_interval` of the options, until the backup succeeds or
+    /// fails, or the `timeout` of the options is reached. Each change of status is reported to the
+    /// progress callback of the options.
+    ///
+    /// # Parameters
+    /// - backend: the backend of the backup
+    /// - backup_id: the id of the backup
+    /// - restore: whether to wait for the restore of the backup, rather than its creation
+    /// - options: the polling options
+    ///
+    /// # Returns
+    /// The final status of the backup if it succeeded, `WeaviateError::BackupFailed` if it failed
+    /// or was cancelled, or `WeaviateError::BackupTimeout` if it did not complete in time.
+    /// Statuses that are not known by this crate, such as those added by newer servers, are
+    /// treated as still in progress, so set a timeout to bound the wait.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::backups::{
+    ///     BackupBackends, BackupCreateRequest, BackupWaitOptions
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let my_request = BackupCreateRequest::builder("doc-test-backup").build();
+    ///     client.backups().create(BackupBackends::FILESYSTEM, &my_request, None).await?;
+    ///
+    ///     let options = BackupWaitOptions::new()
+    ///         .with_poll_interval(Duration::from_secs(5))
+    ///         .with_timeout(Duration::from_secs(600))
+    ///         .with_progress(|status| println!("{:?}", status.status));
+    ///     let res = client.backups().wait_for_completion(
+    ///         BackupBackends::FILESYSTEM,
+    ///         "doc-test-backup",
+    ///         false,
+    ///         &options
+    ///     ).await?;
+    ///     println!("{:#?}", res);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_for_completion(
         &self,
         backend: BackupBackends,
         backup_id: &str,
         restore: bool,
-    ) -> Result<BackupStatus, WeaviateError> {
+        options: &BackupWaitOptions,
+    ) -> Result<BackupStatusResponse, WeaviateError> {
+        let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+        let mut last_status = None;
         loop {
-            let res = self.get_backup_status(backend, backup_id, restore).await?;
-            if res.status == BackupStatus::SUCCESS {
-                return Ok(BackupStatus::SUCCESS);
-            } else if res.status == BackupStatus::FAILED {
-                return Err(WeaviateError::BackupFailed);
+            let res = self
+                .get_backup_status(backend.clone(), backup_id, restore)
+                .await?;
+            if last_status.as_ref() != Some(&res.status) {
+                last_status = Some(res.status.clone());
+                options.report(&res);
             }
+            match res.status {
+                BackupStatus::SUCCESS => return Ok(res),
+                BackupStatus::FAILED | BackupStatus::CANCELED => {
+                    return Err(WeaviateError::BackupFailed)
+                }
+                // statuses that are not known by this crate are assumed to still be in progress
+                _ => {}
+            }
+
+            let mut interval = options.poll_interval;
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Err(WeaviateError::BackupTimeout {
+                        backup_id: backup_id.into(),
+                        status: res.status,
+                    });
+                }
+                interval = interval.min(remaining);
+            }
+            tokio::time::sleep(interval).await;
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
     use crate::{
+        error::WeaviateError,
         models::backups::{
             BackupBackends, BackupCreateRequest, BackupResponse, BackupRestoreRequest,
-            BackupStatus, BackupStatusResponse,
+            BackupStatus, BackupStatusResponse, BackupWaitOptions,
         },
         WeaviateClient,
     };
@@ -234,7 +404,7 @@ mod tests {
         BackupStatusResponse {
             id: "abcd".into(),
             path: None,
-            backend: BackupBackends::FILESYSTEM.value().into(),
+            backend: BackupBackends::FILESYSTEM,
             status,
         }
     }
@@ -307,7 +477,7 @@ mod tests {
         let mock = mock_post(&mut mock_server, "/v1/backups/filesystem", 200, &out_str).await;
         let res = client
             .backups()
-            .create(BackupBackends::FILESYSTEM, &req, false)
+            .create(BackupBackends::FILESYSTEM, &req, None)
             .await;
         mock.assert();
         assert!(res.is_ok());
@@ -321,7 +491,7 @@ mod tests {
         let mock = mock_post(&mut mock_server, "/v1/backups/filesystem", 404, "").await;
         let res = client
             .backups()
-            .create(BackupBackends::FILESYSTEM, &req, false)
+            .create(BackupBackends::FILESYSTEM, &req, None)
             .await;
         mock.assert();
         assert!(res.is_err());
@@ -345,7 +515,11 @@ mod tests {
         .await;
         let res = client
             .backups()
-            .create(BackupBackends::FILESYSTEM, &req, true)
+            .create(
+                BackupBackends::FILESYSTEM,
+                &req,
+                Some(&BackupWaitOptions::new()),
+            )
             .await;
         mock.assert();
         mock2.assert();
@@ -360,12 +534,47 @@ mod tests {
         let mock = mock_post(&mut mock_server, "/v1/backups/filesystem", 404, "").await;
         let res = client
             .backups()
-            .create(BackupBackends::FILESYSTEM, &req, true)
+            .create(
+                BackupBackends::FILESYSTEM,
+                &req,
+                Some(&BackupWaitOptions::new()),
+            )
             .await;
         mock.assert();
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_create_backup_wait_timeout() {
+        let req = test_create_backup_request();
+        let out = test_backup_response(BackupStatus::STARTED);
+        let out_str = serde_json::to_string(&out).unwrap();
+        let status_str = serde_json::to_string(&test_backup_status(BackupStatus::STARTED)).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let _mock = mock_post(&mut mock_server, "/v1/backups/filesystem", 200, &out_str).await;
+        let _mock2 = mock_get(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd",
+            200,
+            &status_str,
+        )
+        .await;
+        let options = BackupWaitOptions::new()
+            .with_poll_interval(Duration::from_millis(10))
+            .with_timeout(Duration::from_millis(50));
+        let res = client
+            .backups()
+            .create(BackupBackends::FILESYSTEM, &req, Some(&options))
+            .await;
+        assert!(matches!(
+            res,
+            Err(WeaviateError::BackupTimeout {
+                status: BackupStatus::STARTED,
+                ..
+            })
+        ));
+    }
+
     #[tokio::test]
     async fn test_restore_backup_ok() {
         let req = test_restore_backup_request();
@@ -381,7 +590,7 @@ mod tests {
         .await;
         let res = client
             .backups()
-            .restore(BackupBackends::FILESYSTEM, "abcd", &req, false)
+            .restore(BackupBackends::FILESYSTEM, "abcd", &req, None)
             .await;
         mock.assert();
         assert!(res.is_ok());
@@ -401,7 +610,7 @@ mod tests {
         .await;
         let res = client
             .backups()
-            .restore(BackupBackends::FILESYSTEM, "abcd", &req, false)
+            .restore(BackupBackends::FILESYSTEM, "abcd", &req, None)
             .await;
         mock.assert();
         assert!(res.is_err());
@@ -431,7 +640,12 @@ mod tests {
         .await;
         let res = client
             .backups()
-            .restore(BackupBackends::FILESYSTEM, "abcd", &req, true)
+            .restore(
+                BackupBackends::FILESYSTEM,
+                "abcd",
+                &req,
+                Some(&BackupWaitOptions::new()),
+            )
             .await;
         mock.assert();
         mock2.assert();
@@ -452,9 +666,242 @@ mod tests {
         .await;
         let res = client
             .backups()
-            .restore(BackupBackends::FILESYSTEM, "abcd", &req, true)
+            .restore(
+                BackupBackends::FILESYSTEM,
+                "abcd",
+                &req,
+                Some(&BackupWaitOptions::new()),
+            )
+            .await;
+        mock.assert();
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_progress() {
+        let started = serde_json::to_string(&test_backup_status(BackupStatus::STARTED)).unwrap();
+        let success = serde_json::to_string(&test_backup_status(BackupStatus::SUCCESS)).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("GET", "/v1/backups/filesystem/abcd")
+            .with_status(200)
+            .with_body(&started)
+            .expect(2)
+            .create();
+        let mock2 = mock_get(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd",
+            200,
+            &success,
+        )
+        .await;
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let options = {
+            let seen = seen.clone();
+            BackupWaitOptions::new()
+                .with_poll_interval(Duration::from_millis(1))
+                .with_progress(move |status| seen.lock().unwrap().push(status.status.clone()))
+        };
+        let res = client
+            .backups()
+            .wait_for_completion(BackupBackends::FILESYSTEM, "abcd", false, &options)
+            .await;
+        mock.assert();
+        mock2.assert();
+        assert_eq!(res.unwrap().status, BackupStatus::SUCCESS);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![BackupStatus::STARTED, BackupStatus::SUCCESS]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_unknown_status() {
+        let mut pending = serde_json::to_value(test_backup_status(BackupStatus::STARTED)).unwrap();
+        pending["status"] = "REPLICATING".into();
+        let success = serde_json::to_string(&test_backup_status(BackupStatus::SUCCESS)).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("GET", "/v1/backups/filesystem/abcd")
+            .with_status(200)
+            .with_body(pending.to_string())
+            .expect(1)
+            .create();
+        let mock2 = mock_get(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd",
+            200,
+            &success,
+        )
+        .await;
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let options = {
+            let seen = seen.clone();
+            BackupWaitOptions::new()
+                .with_poll_interval(Duration::from_millis(1))
+                .with_progress(move |status| seen.lock().unwrap().push(status.status.clone()))
+        };
+        let res = client
+            .backups()
+            .wait_for_completion(BackupBackends::FILESYSTEM, "abcd", false, &options)
+            .await;
+        mock.assert();
+        mock2.assert();
+        assert_eq!(res.unwrap().status, BackupStatus::SUCCESS);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                BackupStatus::Unknown("REPLICATING".into()),
+                BackupStatus::SUCCESS
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_timeout() {
+        let out_str =
+            serde_json::to_string(&test_backup_status(BackupStatus::TRANSFERRING)).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let _mock = mock_get(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd",
+            200,
+            &out_str,
+        )
+        .await;
+        let options = BackupWaitOptions::new()
+            .with_poll_interval(Duration::from_millis(10))
+            .with_timeout(Duration::from_millis(50));
+        let res = client
+            .backups()
+            .wait_for_completion(BackupBackends::FILESYSTEM, "abcd", false, &options)
+            .await;
+        assert!(matches!(
+            res,
+            Err(WeaviateError::BackupTimeout {
+                status: BackupStatus::TRANSFERRING,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_failed() {
+        let out_str = serde_json::to_string(&test_backup_status(BackupStatus::FAILED)).unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(
+            &mut mock_server,
+            "/v1/backups/filesystem/abcd/restore",
+            200,
+            &out_str,
+        )
+        .await;
+        let res = client
+            .backups()
+            .wait_for_completion(
+                BackupBackends::FILESYSTEM,
+                "abcd",
+                true,
+                &BackupWaitOptions::default(),
+            )
             .await;
         mock.assert();
+        assert!(matches!(res, Err(WeaviateError::BackupFailed)));
+    }
+
+    #[tokio::test]
+    async fn test_list_backups_ok() {
+        let out_str = serde_json::to_string(&serde_json::json!([
+            {"id": "abcd", "classes": ["Article"], "status": "SUCCESS"},
+            {"id": "efgh", "status": "CANCELED", "startedAt": "2024-01-01T00:00:00Z"}
+        ]))
+        .unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/backups/filesystem", 200, &out_str).await;
+        let res = client.backups().list(BackupBackends::FILESYSTEM).await;
+        mock.assert();
+        let res = res.unwrap();
+        assert_eq!(res.len(), 2);
+        assert_eq!(res[0].classes, vec!["Article"]);
+        assert_eq!(res[1].status, BackupStatus::CANCELED);
+        assert!(res[1].started_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_list_backups_custom_backend_escaped() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(
+            &mut mock_server,
+            "/v1/backups/my%20backend%2F..%2Fnodes",
+            200,
+            "[]",
+        )
+        .await;
+        let res = client
+            .backups()
+            .list(BackupBackends::custom("my backend/../nodes"))
+            .await;
+        mock.assert();
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_backups_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/backups/filesystem", 500, "").await;
+        let res = client.backups().list(BackupBackends::FILESYSTEM).await;
+        mock.assert();
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn test_cancel_backup_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("DELETE", "/v1/backups/filesystem/abcd")
+            .with_status(204)
+            .create();
+        let res = client
+            .backups()
+            .cancel(BackupBackends::FILESYSTEM, "abcd")
+            .await;
+        mock.assert();
+        assert!(res.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_backup_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("DELETE", "/v1/backups/filesystem/abcd")
+            .with_status(422)
+            .create();
+        let res = client
+            .backups()
+            .cancel(BackupBackends::FILESYSTEM, "abcd")
+            .await;
+        mock.assert();
+        assert!(res.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_backup_custom_backend_ok() {
+        let req = test_create_backup_request();
+        let mut out = serde_json::to_value(test_backup_response(BackupStatus::STARTED)).unwrap();
+        out["backend"] = "backup-minio".into();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(
+            &mut mock_server,
+            "/v1/backups/backup-minio",
+            200,
+            &out.to_string(),
+        )
+        .await;
+        let res = client
+            .backups()
+            .create(BackupBackends::custom("backup-minio"), &req, None)
+            .await;
+        mock.assert();
+        assert_eq!(res.unwrap().backend, BackupBackends::custom("backup-minio"));
+    }
 }
@@ -102,6 +102,9 @@ impl<'a> Batch<'a> {
             .await?
             .json()
             .await?;
+        for object in &objects.objects {
+            self.client.invalidate_class(&object.class);
+        }
         Ok(res)
     }
 
@@ -169,6 +172,7 @@ impl<'a> Batch<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(&request_body.matches.class);
         Ok(res)
     }
 
@@ -228,19 +232,14 @@ impl<'a> Batch<'a> {
         tenant: Option<&str>,
     ) -> Result<BatchAddReferencesResponse, WeaviateError> {
         let mut converted: Vec<serde_json::Value> = Vec::new();
+        let mut classes: Vec<String> = Vec::new();
         for reference in references.0 {
+            if !classes.contains(&reference.from_class_name) {
+                classes.push(reference.from_class_name.clone());
+            }
             let new_ref = serde_json::json!({
-                "from": format!(
-                    "weaviate://localhost/{}/{}/{}",
-                    reference.from_class_name,
-                    reference.from_uuid,
-                    reference.from_property_name
-                ),
-                "to": format!(
-                    "weaviate://localhost/{}/{}",
-                    reference.to_class_name,
-                    reference.to_uuid
-                ),
+                "from": reference.from_beacon(),
+                "to": reference.to_beacon(),
             });
             converted.push(new_ref);
         }
@@ -267,6 +266,9 @@ impl<'a> Batch<'a> {
             .await?
             .json()
             .await?;
+        for class_name in &classes {
+            self.client.invalidate_class(class_name);
+        }
         Ok(res)
     }
 }
@@ -474,6 +476,37 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_references_batch_add_beacons() {
+        let refs = test_references();
+        let from = "weaviate://localhost/Test/36ddd591-2dee-4e7e-a3cc-eb86d30a4303/testProp";
+        let to = "weaviate://localhost/Other/6bb06a43-e7f0-393e-9ecf-3c0f4e129064";
+        let res_str = serde_json::to_string(&serde_json::json!([{
+            "from": from,
+            "to": to,
+            "result": {"status": "SUCCESS"}
+        }]))
+        .unwrap();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("POST", "/v1/batch/references")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!([
+                {"from": from, "to": to}
+            ])))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(res_str)
+            .create();
+        let res = client.batch().references_batch_add(refs, None, None).await;
+        mock.assert();
+        let res = res.unwrap();
+        assert_eq!(res.0[0].from, Some(from.parse().unwrap()));
+        assert_eq!(
+            res.0[0].to.as_ref().and_then(|to| to.class_name.as_deref()),
+            Some("Other")
+        );
+    }
+
     #[tokio::test]
     async fn test_references_batch_add_err() {
         let refs = test_references();
@@ -1,10 +1,14 @@
 use reqwest::StatusCode;
+use tokio::time::Instant;
 use url::Url;
 use uuid::Uuid;
 
 use crate::{
     error::WeaviateError,
-    models::classification::{ClassificationRequest, ClassificationResponse},
+    models::classification::{
+        ClassificationRequest, ClassificationResponse, ClassificationStatus,
+        ClassificationWaitOptions,
+    },
     ResponseExt, WeaviateClient,
 };
 
@@ -114,12 +118,91 @@ impl<'a> Classification<'a> {
             .await?;
         Ok(res)
     }
+
+    /// Wait for a classification to complete, polling its status.
+    ///
+    /// Statuses that are not known by this crate, such as those added by newer servers, are
+    /// treated as still in progress, so set a timeout to bound the wait. This is the same as
+    /// `Backups::wait_for_completion`.
+    ///
+    /// # Errors
+    /// - `WeaviateError::ClassificationFailed` if the classification failed, with its error
+    /// - `WeaviateError::ClassificationTimeout` if the classification did not complete in time
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::classification::{
+    ///     ClassificationRequest,
+    ///     ClassificationSettings,
+    ///     ClassificationWaitOptions
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///
+    ///     let req = ClassificationRequest::builder()
+    ///         .with_classification_settings(ClassificationSettings::knn(3))
+    ///         .with_class("Article")
+    ///         .with_based_on_properties(vec!["summary"])
+    ///         .with_classify_properties(vec!["hasPopularity"])
+    ///         .build();
+    ///     let res = client.classification().schedule(req).await?;
+    ///
+    ///     let options = ClassificationWaitOptions::new().with_timeout(Duration::from_secs(600));
+    ///     let res = client.classification().wait_for_completion(res.id, &options).await?;
+    ///     println!("classified {:?} objects", res.meta.count_succeeded);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn wait_for_completion(
+        &self,
+        id: Uuid,
+        options: &ClassificationWaitOptions,
+    ) -> Result<ClassificationResponse, WeaviateError> {
+        let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+        loop {
+            let res = self.get(id).await?;
+            match res.status {
+                ClassificationStatus::COMPLETED => return Ok(res),
+                ClassificationStatus::FAILED => {
+                    return Err(WeaviateError::ClassificationFailed {
+                        id,
+                        error: res.error,
+                    })
+                }
+                // statuses that are not known by this crate are assumed to still be in progress
+                ClassificationStatus::RUNNING | ClassificationStatus::Unknown(_) => {}
+            }
+
+            let mut interval = options.poll_interval;
+            if let Some(deadline) = deadline {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return Err(WeaviateError::ClassificationTimeout {
+                        id,
+                        status: res.status,
+                    });
+                }
+                interval = interval.min(remaining);
+            }
+            tokio::time::sleep(interval).await;
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use crate::{
-        models::classification::{ClassificationRequest, ClassificationType},
+        error::WeaviateError,
+        models::classification::{
+            ClassificationRequest, ClassificationStatus, ClassificationType,
+            ClassificationWaitOptions,
+        },
         WeaviateClient,
     };
     use uuid::Uuid;
@@ -147,6 +230,25 @@ mod tests {
             .build()
     }
 
+    fn test_classification_res(id: Uuid, status: &str) -> String {
+        serde_json::json!({
+            "id": id,
+            "class": "Test",
+            "classifyProperties": ["hasPopularity"],
+            "basedOnProperties": ["testProp"],
+            "status": status,
+            "error": if status == "failed" { "no training data" } else { "" },
+            "meta": {
+                "started": "2023-11-01T12:00:00.000Z",
+                "completed": "0001-01-01T00:00:00.000Z"
+            },
+            "type": "knn",
+            "settings": {"k": 3},
+            "filters": {}
+        })
+        .to_string()
+    }
+
     async fn mock_post(
         server: &mut mockito::ServerGuard,
         endpoint: &str,
@@ -202,4 +304,104 @@ mod tests {
         mock.assert();
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn test_classification_wait_for_completion_ok() {
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/classifications/{uuid}");
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(
+            &mut mock_server,
+            &url,
+            200,
+            &test_classification_res(uuid, "completed"),
+        )
+        .await;
+        let res = client
+            .classification()
+            .wait_for_completion(uuid, &ClassificationWaitOptions::new())
+            .await;
+        mock.assert();
+        assert_eq!(res.unwrap().status, ClassificationStatus::COMPLETED);
+    }
+
+    #[tokio::test]
+    async fn test_classification_wait_for_completion_failed() {
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/classifications/{uuid}");
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(
+            &mut mock_server,
+            &url,
+            200,
+            &test_classification_res(uuid, "failed"),
+        )
+        .await;
+        let res = client
+            .classification()
+            .wait_for_completion(uuid, &ClassificationWaitOptions::new())
+            .await;
+        mock.assert();
+        assert!(matches!(
+            res,
+            Err(WeaviateError::ClassificationFailed { error: Some(error), .. })
+                if error == "no training data"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_classification_wait_for_completion_timeout() {
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/classifications/{uuid}");
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_server
+            .mock("GET", url.as_str())
+            .with_status(200)
+            .with_body(test_classification_res(uuid, "running"))
+            .expect_at_least(2)
+            .create();
+        let options = ClassificationWaitOptions::new()
+            .with_poll_interval(Duration::from_millis(10))
+            .with_timeout(Duration::from_millis(50));
+        let res = client
+            .classification()
+            .wait_for_completion(uuid, &options)
+            .await;
+        mock.assert();
+        assert!(matches!(
+            res,
+            Err(WeaviateError::ClassificationTimeout {
+                status: ClassificationStatus::RUNNING,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_classification_wait_for_completion_unknown_status() {
+        let uuid = Uuid::new_v4();
+        let url = format!("/v1/classifications/{uuid}");
+        let (mut mock_server, client) = get_test_harness().await;
+        let paused = mock_server
+            .mock("GET", url.as_str())
+            .with_status(200)
+            .with_body(test_classification_res(uuid, "paused"))
+            .expect(1)
+            .create();
+        let completed = mock_get(
+            &mut mock_server,
+            &url,
+            200,
+            &test_classification_res(uuid, "completed"),
+        )
+        .await;
+        let options = ClassificationWaitOptions::new().with_poll_interval(Duration::from_millis(1));
+        let res = client
+            .classification()
+            .wait_for_completion(uuid, &options)
+            .await;
+        paused.assert();
+        completed.assert();
+        assert_eq!(res.unwrap().status, ClassificationStatus::COMPLETED);
+    }
 }
@@ -1,7 +1,7 @@
 use reqwest::{StatusCode, Url};
 
 use crate::error::WeaviateError;
-use crate::models::nodes::MultiNodes;
+use crate::models::nodes::{MultiNodes, NodeOutput};
 use crate::{ResponseExt, WeaviateClient};
 
 /// All nodes related endpoints and functionality described in
@@ -29,19 +29,42 @@ impl<'a> Nodes<'a> {
 
     /// Get the node status for all nodes in the Weaviate instance.
     ///
+    /// # Parameters
+    /// - class_name: only include the shards of this class
+    /// - output: the amount of detail in the response, the server default is
+    ///   `NodeOutput::Minimal`, which does not include the shards
+    ///
     /// # Examples
     /// ```no_run
     /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::nodes::NodeOutput;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>>{
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
-    ///     let res = client.nodes().get_nodes_status().await?;
+    ///     let res = client.nodes().get_nodes_status(None, None).await?;
+    ///
+    ///     let res = client
+    ///         .nodes()
+    ///         .get_nodes_status(Some("Article"), Some(NodeOutput::Verbose))
+    ///         .await?;
     ///     Ok(())
     /// }
     /// ```
-    pub async fn get_nodes_status(&self) -> Result<MultiNodes, WeaviateError> {
-        let endpoint = self.endpoint()?;
+    pub async fn get_nodes_status(
+        &self,
+        class_name: Option<&str>,
+        output: Option<NodeOutput>,
+    ) -> Result<MultiNodes, WeaviateError> {
+        let mut endpoint = self.endpoint()?;
+        if let Some(class_name) = class_name {
+            endpoint = endpoint.join(class_name)?;
+        }
+        if let Some(output) = output {
+            endpoint
+                .query_pairs_mut()
+                .append_pair("output", output.value());
+        }
         let res = self
             .client
             .get(endpoint)
@@ -57,7 +80,10 @@ impl<'a> Nodes<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{models::nodes::MultiNodes, WeaviateClient};
+    use crate::{
+        models::nodes::{MultiNodes, NodeOutput, NodeStatus, VectorIndexingStatus},
+        WeaviateClient,
+    };
 
     async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
         let mock_server = mockito::Server::new_async().await;
@@ -184,7 +210,7 @@ mod tests {
         let nodes = test_nodes();
         let nodes_str = serde_json::to_string(&nodes).unwrap();
         let mock = mock_get(&mut mock_server, "/v1/nodes/", 200, &nodes_str).await;
-        let res = client.nodes().get_nodes_status().await;
+        let res = client.nodes().get_nodes_status(None, None).await;
         mock.assert();
         assert!(res.is_ok());
         assert_eq!(res.unwrap().nodes.len(), nodes.nodes.len());
@@ -194,8 +220,37 @@ mod tests {
     async fn test_get_nodes_status_err() {
         let (mut mock_server, client) = get_test_harness().await;
         let mock = mock_get(&mut mock_server, "/v1/nodes/", 404, "").await;
-        let res = client.nodes().get_nodes_status().await;
+        let res = client.nodes().get_nodes_status(None, None).await;
         mock.assert();
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn test_get_nodes_status_verbose_class_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let nodes_str = serde_json::to_string(&test_nodes()).unwrap();
+        let mock = mock_server
+            .mock("GET", "/v1/nodes/TestArticle")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "output".into(),
+                "verbose".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&nodes_str)
+            .create();
+        let res = client
+            .nodes()
+            .get_nodes_status(Some("TestArticle"), Some(NodeOutput::Verbose))
+            .await;
+        mock.assert();
+        let res = res.unwrap();
+        assert_eq!(res.nodes[0].status, Some(NodeStatus::HEALTHY));
+        let shards = &res.nodes[0].shards.as_ref().unwrap().0;
+        assert_eq!(
+            shards[0].vector_indexing_status,
+            Some(VectorIndexingStatus::READY)
+        );
+        assert_eq!(res.nodes[1].stats.as_ref().unwrap().object_count, Some(1));
+    }
 }
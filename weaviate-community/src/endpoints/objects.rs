@@ -3,7 +3,7 @@ use uuid::Uuid;
 
 use crate::error::{QueryError, WeaviateError};
 use crate::models::objects::{
-    ConsistencyLevel, MultiObjects, Object, ObjectListParameters, Reference,
+    Beacon, ConsistencyLevel, MultiObjects, Object, ObjectListParameters, OrderBy, Reference,
 };
 use crate::{ResponseExt, WeaviateClient};
 
@@ -78,7 +78,7 @@ impl<'a> Objects<'a> {
         }
         if let Some(a) = &parameters.after {
             endpoint.query_pairs_mut().append_pair("after", a);
-            if parameters.after.is_none() {
+            if parameters.class_name.is_none() {
                 return Err(QueryError::InvalidCombination(&["class", "after"]).into());
             }
             // raise an error if offset or sort are some
@@ -92,13 +92,16 @@ impl<'a> Objects<'a> {
         if let Some(i) = parameters.include {
             endpoint.query_pairs_mut().append_pair("include", &i);
         }
-        if let Some(s) = parameters.sort {
-            let values = s.join(",");
-            endpoint.query_pairs_mut().append_pair("sort", &values);
-        }
-        if let Some(o) = parameters.order {
-            let values = o.join(",");
-            endpoint.query_pairs_mut().append_pair("order", &values);
+        if let Some(sort) = parameters.sort {
+            let paths: Vec<String> = sort.iter().map(|s| s.path.join(".")).collect();
+            let orders: Vec<&str> = sort
+                .iter()
+                .map(|s| s.order.as_ref().unwrap_or(&OrderBy::ASC).value())
+                .collect();
+            endpoint
+                .query_pairs_mut()
+                .append_pair("sort", &paths.join(","))
+                .append_pair("order", &orders.join(","));
         }
 
         let res: MultiObjects = self
@@ -164,6 +167,7 @@ impl<'a> Objects<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(&new_object.class);
         Ok(res)
     }
 
@@ -175,6 +179,7 @@ impl<'a> Objects<'a> {
     /// - include: extra fields to include (classification, vector)
     /// - consistency_level: the consistency_level of the object
     /// - tenant_key: the tenant that the object is associated with
+    /// - node_name: the name of the node to read the object from, in a replicated cluster
     ///
     /// # Example
     /// ```
@@ -187,7 +192,7 @@ impl<'a> Objects<'a> {
     ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
     ///     let res = client
     ///         .objects()
-    ///         .get("TestListObject", &uuid, None, None, None).await;
+    ///         .get("TestListObject", &uuid, None, None, None, None).await;
     ///     Ok(())
     /// }
     /// ```
@@ -198,6 +203,7 @@ impl<'a> Objects<'a> {
         include: Option<&str>,
         consistency_level: Option<ConsistencyLevel>,
         tenant_key: Option<&str>,
+        node_name: Option<&str>,
     ) -> Result<Object, WeaviateError> {
         let path = format!("{class_name}/{id}");
         let mut endpoint = self.endpoint()?.join(&path)?;
@@ -214,6 +220,9 @@ impl<'a> Objects<'a> {
             // multi tenancy must be enabled first
             endpoint.query_pairs_mut().append_pair("include", i);
         }
+        if let Some(n) = node_name {
+            endpoint.query_pairs_mut().append_pair("node_name", n);
+        }
 
         let res: Object = self
             .client
@@ -236,6 +245,7 @@ impl<'a> Objects<'a> {
     /// - id: the uuid of the object
     /// - consistency_level: the consistency_level of the object
     /// - tenant_name: the name of the tenant the object is associated with
+    /// - node_name: the name of the node to check for the object on, in a replicated cluster
     ///
     /// # Example
     /// ```
@@ -248,7 +258,7 @@ impl<'a> Objects<'a> {
     ///     let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
     ///     let res = client
     ///         .objects()
-    ///         .exists("TestListObject", &uuid, None, None).await;
+    ///         .exists("TestListObject", &uuid, None, None, None).await;
     ///     Ok(())
     /// }
     /// ```
@@ -258,6 +268,7 @@ impl<'a> Objects<'a> {
         id: &Uuid,
         consistency_level: Option<ConsistencyLevel>,
         tenant_name: Option<&str>,
+        node_name: Option<&str>,
     ) -> Result<bool, WeaviateError> {
         let path = format!("{class_name}/{id}");
         let mut endpoint = self.endpoint()?.join(&path)?;
@@ -270,6 +281,9 @@ impl<'a> Objects<'a> {
             // multi tenancy must be enabled first
             endpoint.query_pairs_mut().append_pair("tenant", t);
         }
+        if let Some(n) = node_name {
+            endpoint.query_pairs_mut().append_pair("node_name", n);
+        }
 
         let _res = self
             .client
@@ -334,6 +348,7 @@ impl<'a> Objects<'a> {
             .await?
             .check_status(StatusCode::NO_CONTENT)
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(true)
     }
 
@@ -400,6 +415,7 @@ impl<'a> Objects<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(res)
     }
 
@@ -453,6 +469,7 @@ impl<'a> Objects<'a> {
             .await?
             .check_status(StatusCode::NO_CONTENT)
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(true)
     }
 
@@ -553,12 +570,12 @@ impl<'a> Objects<'a> {
                 .query_pairs_mut()
                 .append_pair("consistency_level", cl.value());
         }
-        if let Some(t) = reference.tenant_name {
+        if let Some(t) = &reference.tenant_name {
             // multi tenancy must be enabled first
-            endpoint.query_pairs_mut().append_pair("tenant", &t);
+            endpoint.query_pairs_mut().append_pair("tenant", t);
         }
         let payload = serde_json::json!({
-            "beacon": format!("weaviate://localhost/{}/{}", reference.to_class_name, reference.to_uuid),
+            "beacon": reference.to_beacon(),
         });
 
         let _res = self
@@ -569,6 +586,7 @@ impl<'a> Objects<'a> {
             .await?
             .check_status(StatusCode::OK)
             .await?;
+        self.client.invalidate_class(&reference.from_class_name);
         Ok(true)
     }
 
@@ -643,7 +661,7 @@ impl<'a> Objects<'a> {
         let mut beacons = Vec::new();
         for (class_name, id) in to_class_names.iter().zip(to_uuids.iter()) {
             beacons.push(serde_json::json!({
-                "beacon": format!("weaviate://localhost/{}/{}", class_name, id)
+                "beacon": Beacon::new(class_name, **id)
             }));
         }
         let payload = serde_json::json!(beacons);
@@ -658,6 +676,7 @@ impl<'a> Objects<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(from_class_name);
         Ok(res)
     }
 
@@ -710,13 +729,13 @@ impl<'a> Objects<'a> {
                 .query_pairs_mut()
                 .append_pair("consistency_level", cl.value());
         }
-        if let Some(t) = reference.tenant_name {
+        if let Some(t) = &reference.tenant_name {
             // multi tenancy must be enabled first
-            endpoint.query_pairs_mut().append_pair("tenant", &t);
+            endpoint.query_pairs_mut().append_pair("tenant", t);
         }
 
         let payload = serde_json::json!({
-            "beacon": format!("weaviate://localhost/{}/{}", reference.to_class_name, reference.to_uuid),
+            "beacon": reference.to_beacon(),
         });
 
         let _res = self
@@ -727,6 +746,7 @@ impl<'a> Objects<'a> {
             .await?
             .check_status(StatusCode::NO_CONTENT)
             .await?;
+        self.client.invalidate_class(&reference.from_class_name);
         Ok(true)
     }
 }
@@ -736,7 +756,9 @@ mod tests {
     use uuid::Uuid;
 
     use crate::{
-        models::objects::{MultiObjects, Object, ObjectListParameters, Reference},
+        models::objects::{
+            ConsistencyLevel, MultiObjects, Object, ObjectListParameters, Reference, Sort,
+        },
         WeaviateClient,
     };
 
@@ -857,6 +879,44 @@ mod tests {
         assert_eq!(objects.objects[0].class, res.unwrap().objects[0].class);
     }
 
+    #[tokio::test]
+    async fn test_list_sort_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let objects = test_objects("Test");
+        let objects_str = serde_json::to_string(&objects).unwrap();
+        let mock = mock_server
+            .mock("GET", "/v1/objects/")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("sort".into(), "title,_creationTimeUnix".into()),
+                mockito::Matcher::UrlEncoded("order".into(), "asc,desc".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&objects_str)
+            .create();
+        let params = ObjectListParameters::builder()
+            .with_sort(vec![
+                Sort::property("title"),
+                Sort::creation_time().descending(),
+            ])
+            .build();
+        let res = client.objects().list(params).await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_list_sort_after_err() {
+        let (_mock_server, client) = get_test_harness().await;
+        let params = ObjectListParameters::builder()
+            .with_class_name("Test")
+            .with_after("dcfbe06f-fb69-48d7-9a13-e8e78e422486")
+            .with_sort(vec![Sort::property("title")])
+            .build();
+        let res = client.objects().list(params).await;
+        assert!(res.is_err());
+    }
+
     #[tokio::test]
     async fn test_list_err() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -897,7 +957,10 @@ mod tests {
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
         let mock = mock_get(&mut mock_server, &url, 200, &object_str).await;
-        let res = client.objects().get("Test", &uuid, None, None, None).await;
+        let res = client
+            .objects()
+            .get("Test", &uuid, None, None, None, None)
+            .await;
         mock.assert();
         assert!(res.is_ok());
         assert_eq!(object.class, res.unwrap().class);
@@ -910,11 +973,45 @@ mod tests {
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
         let mock = mock_get(&mut mock_server, &url, 422, "").await;
-        let res = client.objects().get("Test", &uuid, None, None, None).await;
+        let res = client
+            .objects()
+            .get("Test", &uuid, None, None, None, None)
+            .await;
         mock.assert();
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_get_node_name_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let object = test_object("Test");
+        let object_str = serde_json::to_string(&object).unwrap();
+        let uuid = Uuid::new_v4();
+        let mock = mock_server
+            .mock("GET", format!("/v1/objects/Test/{uuid}").as_str())
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("consistency_level".into(), "ONE".into()),
+                mockito::Matcher::UrlEncoded("node_name".into(), "node1".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&object_str)
+            .create();
+        let res = client
+            .objects()
+            .get(
+                "Test",
+                &uuid,
+                None,
+                Some(ConsistencyLevel::ONE),
+                None,
+                Some("node1"),
+            )
+            .await;
+        mock.assert();
+        assert!(res.is_ok());
+    }
+
     #[tokio::test]
     async fn test_exists_ok() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -922,7 +1019,10 @@ mod tests {
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
         let mock = mock_head(&mut mock_server, &url, 204, "").await;
-        let res = client.objects().exists("Test", &uuid, None, None).await;
+        let res = client
+            .objects()
+            .exists("Test", &uuid, None, None, None)
+            .await;
         mock.assert();
         assert!(res.is_ok());
         assert!(res.unwrap());
@@ -935,7 +1035,10 @@ mod tests {
         let mut url = String::from("/v1/objects/Test/");
         url.push_str(&uuid.to_string());
         let mock = mock_head(&mut mock_server, &url, 422, "").await;
-        let res = client.objects().exists("Test", &uuid, None, None).await;
+        let res = client
+            .objects()
+            .exists("Test", &uuid, None, None, None)
+            .await;
         mock.assert();
         assert!(res.is_err());
     }
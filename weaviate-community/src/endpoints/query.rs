@@ -1,11 +1,14 @@
+use std::collections::HashMap;
+
 use reqwest::{StatusCode, Url};
 use serde::de::DeserializeOwned;
 
 use crate::{
-    error::WeaviateError,
+    error::{QueryError, WeaviateError},
     models::query::{
-        AggregateQuery, ExploreQuery, GetQuery, GraphQLAggregateResponse, GraphQLExploreResponse,
-        GraphQLGetResponse, MaybeError, RawQuery,
+        AggregateQuery, AggregateResult, ExploreQuery, ExploreResult, GetQuery,
+        GraphQLAggregateResponse, GraphQLExploreResponse, GraphQLGetResponse, Group, GroupResult,
+        MaybeError, MultiQuery, MultiQueryResponse, RawQuery,
     },
     ResponseExt, WeaviateClient,
 };
@@ -63,22 +66,62 @@ impl<'a> Query<'a> {
     /// }
     /// ```
     pub async fn get<T: DeserializeOwned>(&self, query: GetQuery) -> Result<T, WeaviateError> {
-        let endpoint = self.endpoint()?;
-        let payload = query.as_payload();
-        let res = self
-            .client
-            .post(endpoint)
-            .json(&payload)
-            .send()
-            .await?
-            .check_status(StatusCode::OK)
-            .await?
-            .json::<MaybeError<GraphQLGetResponse<T>>>()
-            .await?
-            .error_for_error()?;
+        let payload = query.as_payload()?;
+        let res = self.post(payload, Some(query.classes())).await?;
+        let res =
+            serde_json::from_value::<MaybeError<GraphQLGetResponse<T>>>(res)?.error_for_error()?;
         Ok(res.data.get)
     }
 
+    /// Execute a Get{} GraphQL query that uses `groupBy`, returning the typed groups.
+    ///
+    /// The `hits` of each group are deserialized into `T`.
+    ///
+    /// # Parameters
+    /// - query: the query to execute, which must have `groupBy` set
+    ///
+    /// # Errors
+    /// If the query does not have `groupBy` set, a `QueryError::MissingField` is returned.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::query::{GetQuery, GroupBy};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let query = GetQuery::new("Chunk", vec!["text"])
+    ///         .with_near_text("{concepts: [\"vector databases\"]}")
+    ///         .with_group_by(GroupBy::new(vec!["document"], 3, 2));
+    ///     let groups = client.query().get_groups::<serde_json::Value>(query).await?;
+    ///     for group in groups {
+    ///         println!("{}: {} hits", group.grouped_by.value, group.hits.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_groups<T: DeserializeOwned>(
+        &self,
+        query: GetQuery,
+    ) -> Result<Vec<Group<T>>, WeaviateError> {
+        if query.group_by.is_none() {
+            return Err(QueryError::MissingField("groupBy".into()).into());
+        }
+        let class_name = query.class_name.clone();
+        let mut res = self
+            .get::<HashMap<String, Option<Vec<GroupResult<T>>>>>(query)
+            .await?;
+        let groups = res
+            .remove(&class_name)
+            .flatten()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|result| result.additional.group)
+            .collect();
+        Ok(groups)
+    }
+
     /// Execute the Aggregate{} GraphQL query
     ///
     ///
@@ -104,22 +147,48 @@ impl<'a> Query<'a> {
         &self,
         query: AggregateQuery,
     ) -> Result<T, WeaviateError> {
-        let endpoint = self.endpoint()?;
         let payload = query.as_payload();
-        let res = self
-            .client
-            .post(endpoint)
-            .json(&payload)
-            .send()
-            .await?
-            .check_status(StatusCode::OK)
-            .await?
-            .json::<MaybeError<GraphQLAggregateResponse<T>>>()
-            .await?
+        let res = self.post(payload, Some(query.classes())).await?;
+        let res = serde_json::from_value::<MaybeError<GraphQLAggregateResponse<T>>>(res)?
             .error_for_error()?;
         Ok(res.data.aggregate)
     }
 
+    /// Execute the Aggregate{} GraphQL query, returning the typed results.
+    ///
+    /// There is one `AggregateResult` per group when the query is grouped, or a single result
+    /// otherwise.
+    ///
+    /// # Parameters
+    /// - query: the query to execute
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::query::{AggregateField, AggregateQuery};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let query = AggregateQuery::new("Article")
+    ///         .with_meta_count()
+    ///         .with_aggregations(vec![AggregateField::int("wordCount")]);
+    ///     let res = client.query().aggregate_results(query).await?;
+    ///     let mean = res[0].number("wordCount").and_then(|agg| agg.mean);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn aggregate_results(
+        &self,
+        query: AggregateQuery,
+    ) -> Result<Vec<AggregateResult>, WeaviateError> {
+        let class_name = query.class_name.clone();
+        let mut res = self
+            .aggregate::<HashMap<String, Option<Vec<AggregateResult>>>>(query)
+            .await?;
+        Ok(res.remove(&class_name).flatten().unwrap_or_default())
+    }
+
     /// Execute the Explore{} GraphQL query
     ///
     /// # Parameters
@@ -128,14 +197,14 @@ impl<'a> Query<'a> {
     /// # Example
     /// ```no_run
     /// use weaviate_community::WeaviateClient;
-    /// use weaviate_community::models::query::ExploreQuery;
+    /// use weaviate_community::models::query::{ExploreQuery, NearVector};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
     ///     let query = ExploreQuery::new()
     ///         .with_limit(1)
-    ///         .with_near_vector("{vector: [-0.36840257,0.13973749,-0.28994447]}")
+    ///         .with_near_vector(NearVector::new(vec![-0.36840257, 0.13973749, -0.28994447]))
     ///         .with_fields(vec!["className"]);
     ///     let res = client.query().explore::<serde_json::Value>(query).await;
     ///     Ok(())
@@ -161,6 +230,83 @@ impl<'a> Query<'a> {
         Ok(res.data.explore)
     }
 
+    /// Execute the Explore{} GraphQL query, returning the typed results.
+    ///
+    /// If no fields are set on the query, the `beacon`, `className` and `distance` fields are
+    /// requested.
+    ///
+    /// # Parameters
+    /// - query: the query to execute
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::query::{ExploreQuery, NearText};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let query = ExploreQuery::new()
+    ///         .with_limit(5)
+    ///         .with_near_text(NearText::new(vec!["fashion"]).with_distance(0.3));
+    ///     for result in client.query().explore_results(query).await? {
+    ///         println!("{} {} {:?}", result.class_name, result.id(), result.distance);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn explore_results(
+        &self,
+        query: ExploreQuery,
+    ) -> Result<Vec<ExploreResult>, WeaviateError> {
+        self.explore(query.with_default_fields()).await
+    }
+
+    /// Execute several Get{} and Aggregate{} GraphQL queries in a single request.
+    ///
+    /// Each query is sent under its alias, and the result of each query can be retrieved from
+    /// the `MultiQueryResponse` using the same alias. Errors reported by the server for one query
+    /// are only returned when retrieving that query's result.
+    ///
+    /// # Parameters
+    /// - query: the queries to execute
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::WeaviateClient;
+    /// use weaviate_community::models::query::{AggregateQuery, GetQuery, MultiQuery};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    ///     let query = MultiQuery::new()
+    ///         .with_get("questions", GetQuery::new("JeopardyQuestion", vec!["question"]))
+    ///         .with_aggregate("articles", AggregateQuery::new("Article").with_meta_count());
+    ///     let res = client.query().multi(query).await?;
+    ///     let questions = res.get::<Vec<serde_json::Value>>("questions")?;
+    ///     let articles = res.aggregate_results("articles")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn multi(&self, query: MultiQuery) -> Result<MultiQueryResponse, WeaviateError> {
+        let payload = query.as_payload()?;
+        let classes = query
+            .get
+            .iter()
+            .flat_map(|(_, query)| query.classes())
+            .chain(
+                query
+                    .aggregate
+                    .iter()
+                    .flat_map(|(_, query)| query.classes()),
+            )
+            .collect();
+        let res = self.post(payload, Some(classes)).await?;
+        let mut res: MultiQueryResponse = serde_json::from_value(res)?;
+        res.lines = query.alias_lines();
+        Ok(res)
+    }
+
     /// Execute a raw GraphQL query.
     ///
     /// This method has been implemented to allow you to run your own query that doesn't fit in
@@ -187,8 +333,30 @@ impl<'a> Query<'a> {
     /// }
     /// ```
     pub async fn raw(&self, query: RawQuery) -> Result<serde_json::Value, WeaviateError> {
-        let endpoint = self.endpoint()?;
         let payload = query.as_payload();
+        let res = self.post(payload, None).await?;
+        let res =
+            serde_json::from_value::<MaybeError<serde_json::Value>>(res)?.error_for_error()?;
+        Ok(res)
+    }
+
+    /// Send a GraphQL payload, returning the response body.
+    ///
+    /// When the query cache is enabled, the response is read from the cache if present, and
+    /// successful responses are cached. `classes` are the classes the query depends on, or `None`
+    /// if they are not known.
+    async fn post(
+        &self,
+        payload: serde_json::Value,
+        classes: Option<Vec<String>>,
+    ) -> Result<serde_json::Value, WeaviateError> {
+        let cache = self.client.query_cache();
+        let key = payload.to_string();
+        if let Some(res) = cache.and_then(|cache| cache.get(&key)) {
+            return Ok(res);
+        }
+
+        let endpoint = self.endpoint()?;
         let res: serde_json::Value = self
             .client
             .post(endpoint)
@@ -197,9 +365,18 @@ impl<'a> Query<'a> {
             .await?
             .check_status(StatusCode::OK)
             .await?
-            .json::<MaybeError<serde_json::Value>>()
-            .await?
-            .error_for_error()?;
+            .json()
+            .await?;
+
+        if let Some(cache) = cache {
+            if res
+                .get("errors")
+                .filter(|errors| !errors.is_null())
+                .is_none()
+            {
+                cache.insert(key, res.clone(), classes);
+            }
+        }
         Ok(res)
     }
 }
@@ -207,9 +384,22 @@ impl<'a> Query<'a> {
 #[cfg(test)]
 mod tests {
     use crate::models::query::RawQuery;
-    use crate::models::query::{AggregateQuery, ExploreQuery, GetQuery};
+    use crate::models::query::{
+        AggregateField, AggregateQuery, ExploreQuery, GetQuery, GroupBy, MultiQuery, NearVector,
+    };
     use crate::WeaviateClient;
 
+    async fn get_cached_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
+        let mock_server = mockito::Server::new_async().await;
+        let mut host = "http://".to_string();
+        host.push_str(&mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host)
+            .with_query_cache(10, std::time::Duration::from_secs(60))
+            .build()
+            .unwrap();
+        (mock_server, client)
+    }
+
     async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
         let mock_server = mockito::Server::new_async().await;
         let mut host = "http://".to_string();
@@ -344,6 +534,50 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_get_groups_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let exp_res = serde_json::to_string(&serde_json::json!({
+            "data": {
+                "Get": {
+                    "Chunk": [
+                        {
+                            "_additional": {
+                                "group": {
+                                    "id": 0,
+                                    "groupedBy": { "value": "doc-1", "path": ["document"] },
+                                    "count": 1,
+                                    "minDistance": 0.1,
+                                    "maxDistance": 0.1,
+                                    "hits": [{ "text": "chunk", "_additional": { "distance": 0.1 } }]
+                                }
+                            }
+                        }
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+        let mock = mock_post(&mut mock_server, "/v1/graphql/", 200, &exp_res).await;
+        let query = GetQuery::new("Chunk", vec!["text"])
+            .with_near_text("{concepts: [\"databases\"]}")
+            .with_group_by(GroupBy::new(vec!["document"], 3, 2));
+        let res = client.query().get_groups::<serde_json::Value>(query).await;
+        mock.assert();
+        let groups = res.unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].grouped_by.value, "doc-1");
+        assert_eq!(groups[0].hits[0]["text"], "chunk");
+    }
+
+    #[tokio::test]
+    async fn test_get_groups_missing_group_by() {
+        let (_mock_server, client) = get_test_harness().await;
+        let query = GetQuery::new("Chunk", vec!["text"]);
+        let res = client.query().get_groups::<serde_json::Value>(query).await;
+        assert!(res.is_err());
+    }
+
     #[tokio::test]
     async fn test_aggregate_query_ok() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -365,6 +599,30 @@ mod tests {
         assert_eq!(res.unwrap()["Article"].as_array().unwrap().len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_aggregate_results_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(
+            &mut mock_server,
+            "/v1/graphql/",
+            200,
+            &test_aggregate_response(),
+        )
+        .await;
+        let query = AggregateQuery::new("Article")
+            .with_meta_count()
+            .with_aggregations(vec![
+                AggregateField::int("wordCount"),
+                AggregateField::reference("inPublication"),
+            ]);
+        let res = client.query().aggregate_results(query).await;
+        mock.assert();
+        let res = res.unwrap();
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].meta.as_ref().unwrap().count, 4403);
+        assert_eq!(res[0].number("wordCount").unwrap().sum, Some(4253348.0));
+    }
+
     #[tokio::test]
     async fn test_aggregate_query_err() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -382,13 +640,30 @@ mod tests {
         let mock = mock_post(&mut mock_server, "/v1/graphql/", 200, &exp_res).await;
         let query = ExploreQuery::new()
             .with_limit(1)
-            .with_near_vector("{vector: [-0.36840257,0.13973749,-0.28994447]}")
+            .with_near_vector(NearVector::new(vec![-0.36840257, 0.13973749, -0.28994447]))
             .with_fields(vec!["className"]);
         let res = client.query().explore::<serde_json::Value>(query).await;
         mock.assert();
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_explore_results_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let exp_res = test_explore_response().await;
+        let mock = mock_post(&mut mock_server, "/v1/graphql/", 200, &exp_res).await;
+        let query = ExploreQuery::new().with_near_vector(NearVector::new(vec![
+            -0.36840257,
+            0.13973749,
+            -0.28994447,
+        ]));
+        let res = client.query().explore_results(query).await;
+        mock.assert();
+        let res = res.unwrap();
+        assert_eq!(res[0].class_name, "Publication");
+        assert!(res[0].beacon.class_name.is_none());
+    }
+
     #[tokio::test]
     async fn test_explore_query_err() {
         let (_mock_server, client) = get_test_harness().await;
@@ -397,6 +672,52 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[tokio::test]
+    async fn test_multi_query_ok() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let exp_res = serde_json::to_string(&serde_json::json!({
+            "data": {
+                "Get": { "questions": [{ "question": "q" }] },
+                "Aggregate": { "articles": [{ "meta": { "count": 3 } }] }
+            }
+        }))
+        .unwrap();
+        let mock = mock_post(&mut mock_server, "/v1/graphql/", 200, &exp_res).await;
+        let query = MultiQuery::new()
+            .with_get(
+                "questions",
+                GetQuery::new("JeopardyQuestion", vec!["question"]),
+            )
+            .with_aggregate("articles", AggregateQuery::new("Article").with_meta_count());
+        let res = client.query().multi(query).await;
+        mock.assert();
+        let res = res.unwrap();
+        assert_eq!(
+            res.get::<Vec<serde_json::Value>>("questions")
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            res.aggregate_results("articles").unwrap()[0]
+                .meta
+                .as_ref()
+                .unwrap()
+                .count,
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_multi_query_err() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_post(&mut mock_server, "/v1/graphql/", 422, "").await;
+        let query = MultiQuery::new().with_get("q", GetQuery::new("Article", vec!["title"]));
+        let res = client.query().multi(query).await;
+        mock.assert();
+        assert!(res.is_err());
+    }
+
     #[tokio::test]
     async fn test_raw_query_ok() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -424,4 +745,70 @@ mod tests {
         mock.assert();
         assert!(res.is_err());
     }
+
+    #[tokio::test]
+    async fn test_get_query_cached() {
+        let (mut mock_server, client) = get_cached_test_harness().await;
+        let exp_res = test_get_response().await;
+        let mock = mock_server
+            .mock("POST", "/v1/graphql/")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(&exp_res)
+            .expect(3)
+            .create();
+        let delete_mock = mock_server
+            .mock("DELETE", mockito::Matcher::Any)
+            .with_status(204)
+            .create();
+        let query = || {
+            GetQuery::new(
+                "JeopardyQuestion",
+                vec![
+                    "question",
+                    "hasCategory { ... on JeopardyCategory { title }}",
+                ],
+            )
+        };
+
+        // The second query is served from the cache
+        assert!(client
+            .query()
+            .get::<serde_json::Value>(query())
+            .await
+            .is_ok());
+        assert!(client
+            .query()
+            .get::<serde_json::Value>(query())
+            .await
+            .is_ok());
+        assert_eq!(client.query_cache().unwrap().len(), 1);
+
+        // Mutating the class through the client invalidates the cached result
+        let res = client
+            .objects()
+            .delete("JeopardyQuestion", &uuid::Uuid::nil(), None, None)
+            .await;
+        assert!(res.is_ok());
+        assert!(client.query_cache().unwrap().is_empty());
+        assert!(client
+            .query()
+            .get::<serde_json::Value>(query())
+            .await
+            .is_ok());
+
+        // As does invalidating a class that is selected through a reference
+        client
+            .query_cache()
+            .unwrap()
+            .invalidate_class("JeopardyCategory");
+        assert!(client
+            .query()
+            .get::<serde_json::Value>(query())
+            .await
+            .is_ok());
+
+        mock.assert();
+        delete_mock.assert();
+    }
 }
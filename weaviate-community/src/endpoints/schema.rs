@@ -119,6 +119,7 @@ impl<'a> Schema<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(&class.class);
         Ok(res)
     }
 
@@ -147,6 +148,7 @@ impl<'a> Schema<'a> {
             .await?
             .check_status(StatusCode::OK)
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(true)
     }
 
@@ -176,6 +178,7 @@ impl<'a> Schema<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(&class.class);
         Ok(res)
     }
 
@@ -201,6 +204,7 @@ impl<'a> Schema<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(res)
     }
 
@@ -242,6 +246,7 @@ impl<'a> Schema<'a> {
             .await?
             .check_status(StatusCode::OK)
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(Shard {
             name: shard_name.into(),
             status,
@@ -287,6 +292,7 @@ impl<'a> Schema<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(Tenants { tenants: res })
     }
 
@@ -309,6 +315,7 @@ impl<'a> Schema<'a> {
             .await?
             .check_status(StatusCode::OK)
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(true)
     }
 
@@ -337,6 +344,7 @@ impl<'a> Schema<'a> {
             .await?
             .json()
             .await?;
+        self.client.invalidate_class(class_name);
         Ok(Tenants { tenants: res })
     }
 }
@@ -596,6 +604,34 @@ mod tests {
         assert_eq!(shards.shards[0].name, res.unwrap().shards[0].name);
     }
 
+    #[tokio::test]
+    async fn test_get_shards_statuses() {
+        let shards_str = serde_json::json!([
+            {"name": "abcd", "status": "INDEXING"},
+            {"name": "efgh", "status": "LOADING"},
+            {"name": "ijkl", "status": "LAZY_LOADING"}
+        ])
+        .to_string();
+        let (mut mock_server, client) = get_test_harness().await;
+        let mock = mock_get(&mut mock_server, "/v1/schema/Test/shards", 200, &shards_str).await;
+        let res = client.schema().get_shards("Test").await;
+        mock.assert();
+        let statuses: Vec<ShardStatus> = res
+            .unwrap()
+            .shards
+            .into_iter()
+            .map(|shard| shard.status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ShardStatus::INDEXING,
+                ShardStatus::LOADING,
+                ShardStatus::Unknown("LAZY_LOADING".into())
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_get_shards_err() {
         let (mut mock_server, client) = get_test_harness().await;
@@ -1,3 +1,8 @@
+use crate::models::backups::BackupStatus;
+use crate::models::classification::ClassificationStatus;
+use crate::models::meta::{Capability, ServerVersion};
+use crate::models::query::ValidationProblem;
+
 /// Error for Weaviate operations.
 #[derive(Debug)]
 pub enum WeaviateError {
@@ -14,6 +19,26 @@ pub enum WeaviateError {
         reason: Option<String>,
     },
     BackupFailed,
+    BackupTimeout {
+        backup_id: String,
+        status: BackupStatus,
+    },
+    ClassificationFailed {
+        id: uuid::Uuid,
+        error: Option<String>,
+    },
+    ClassificationTimeout {
+        id: uuid::Uuid,
+        status: ClassificationStatus,
+    },
+    WaitTimeout {
+        timeout: std::time::Duration,
+        reason: String,
+    },
+    Unsupported {
+        capability: Capability,
+        version: ServerVersion,
+    },
     Other(String),
 }
 
@@ -42,6 +67,47 @@ impl std::fmt::Display for WeaviateError {
                 Ok(())
             }
             WeaviateError::BackupFailed => write!(f, "Backup operation failed"),
+            WeaviateError::BackupTimeout { backup_id, status } => write!(
+                f,
+                "Timed out waiting for backup `{backup_id}` to complete, last status was {status:?}"
+            ),
+            WeaviateError::ClassificationFailed { id, error } => {
+                write!(f, "Classification `{id}` failed")?;
+                if let Some(error) = error {
+                    write!(f, ": {error}")?;
+                }
+                Ok(())
+            }
+            WeaviateError::ClassificationTimeout { id, status } => write!(
+                f,
+                "Timed out waiting for classification `{id}` to complete, last status was {status:?}"
+            ),
+            WeaviateError::WaitTimeout { timeout, reason } => write!(
+                f,
+                "Timed out after {timeout:?} waiting for Weaviate, last reason was: {reason}"
+            ),
+            WeaviateError::Unsupported {
+                capability: Capability::Module(name),
+                version,
+            } => write!(
+                f,
+                "Weaviate {version} does not have module `{name}` enabled"
+            ),
+            WeaviateError::Unsupported {
+                capability,
+                version,
+            } => {
+                write!(f, "Weaviate {version} does not support {capability}")?;
+                match capability.minimum_version() {
+                    Some(minimum) if *version < minimum => {
+                        write!(f, ", which requires Weaviate {minimum} or later")
+                    }
+                    _ if *capability == Capability::TenantOffloading => {
+                        write!(f, ", which requires an `offload-*` module")
+                    }
+                    _ => Ok(()),
+                }
+            }
             WeaviateError::Other(msg) => write!(f, "Error: {msg}"),
         }
     }
@@ -90,6 +156,8 @@ pub enum QueryError {
     InvalidCombination(&'static [&'static str]),
     InconsistentLength(usize, usize),
     MissingField(String),
+    InvalidAlias(String),
+    Validation(Vec<ValidationProblem>),
 }
 
 impl std::fmt::Display for QueryError {
@@ -114,8 +182,56 @@ impl std::fmt::Display for QueryError {
             QueryError::MissingField(msg) => {
                 write!(f, "Missing required field: {msg}")
             }
+            QueryError::InvalidAlias(alias) => {
+                write!(
+                    f,
+                    "Invalid query alias `{alias}`: aliases must be unique GraphQL names"
+                )
+            }
+            QueryError::Validation(problems) => {
+                write!(f, "Query does not match the schema: ")?;
+                for (i, problem) in problems.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{problem}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
 impl std::error::Error for QueryError {}
+
+/// Error for a string that is not a valid beacon.
+#[derive(Debug)]
+pub struct BeaconParseError(pub String);
+
+impl std::fmt::Display for BeaconParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid beacon `{}`: expected `weaviate://localhost/[Class/]uuid[/property]`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for BeaconParseError {}
+
+/// Error for a string that is not a valid Weaviate version.
+#[derive(Debug)]
+pub struct VersionParseError(pub String);
+
+impl std::fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid version `{}`: expected `major.minor[.patch][-prerelease]`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for VersionParseError {}
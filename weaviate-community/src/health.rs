@@ -0,0 +1,832 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::error::WeaviateError;
+use crate::models::nodes::{MultiNodes, NodeOutput, NodeShard, NodeStatus, VectorIndexingStatus};
+use crate::models::schema::ShardStatus;
+use crate::WeaviateClient;
+
+/// A change in the health of a Weaviate cluster, reported by a `ClusterHealthWatcher`.
+///
+/// Events are only reported when the health changes, so a problem that persists is reported
+/// once, followed by the matching recovery event once it is resolved.
+#[derive(Debug)]
+pub enum HealthEvent {
+    /// The liveness of the server changed, see `WeaviateClient::is_live`.
+    LivenessChanged { live: bool },
+    /// The readiness of the server changed, see `WeaviateClient::is_ready`.
+    ReadinessChanged { ready: bool },
+    /// A node reported a status other than `HEALTHY` or `INDEXING`.
+    NodeUnhealthy { node: String, status: NodeStatus },
+    /// A node that was unhealthy reported `HEALTHY` or `INDEXING` again.
+    NodeHealthy { node: String },
+    /// The vector index of a shard became read-only.
+    ShardReadOnly {
+        node: String,
+        class: String,
+        shard: String,
+    },
+    /// The vector index of a shard that was read-only became writable again.
+    ShardWritable {
+        node: String,
+        class: String,
+        shard: String,
+    },
+    /// The vector indexing queue of a shard grew above the threshold of the watcher.
+    IndexingQueueAboveThreshold {
+        node: String,
+        class: String,
+        shard: String,
+        length: u64,
+    },
+    /// The vector indexing queue of a shard fell back to the threshold of the watcher or below.
+    IndexingQueueBelowThreshold {
+        node: String,
+        class: String,
+        shard: String,
+        length: u64,
+    },
+    /// The nodes run different versions of Weaviate, given as the nodes running each version.
+    ///
+    /// This is reported again whenever the versions or the nodes running them change.
+    VersionSkew {
+        versions: BTreeMap<String, Vec<String>>,
+    },
+    /// All of the nodes run the same version of Weaviate again.
+    VersionsAligned { version: String },
+    /// The status of the cluster could not be requested, after the previous poll succeeded.
+    PollFailed(WeaviateError),
+    /// The status of the cluster could be requested again after a failed poll.
+    PollRecovered,
+}
+
+/// Watches the health of a Weaviate cluster in the background.
+///
+/// The watcher polls `WeaviateClient::is_live`, `WeaviateClient::is_ready` and the verbose node
+/// status from `Nodes::get_nodes_status`, and reports changes in the health of the cluster as a
+/// `Stream` of `HealthEvent`s.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use weaviate_community::{ClusterHealthWatcher, HealthEvent, WeaviateClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+///     let mut events = ClusterHealthWatcher::new(&client)
+///         .with_interval(Duration::from_secs(30))
+///         .with_queue_threshold(10_000)
+///         .watch();
+///
+///     while let Some(event) = events.next().await {
+///         if let HealthEvent::NodeUnhealthy { node, status } = event {
+///             eprintln!("node {node} is {status:?}");
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClusterHealthWatcher {
+    client: WeaviateClient,
+    interval: Duration,
+    queue_threshold: u64,
+}
+
+impl ClusterHealthWatcher {
+    /// Create a new ClusterHealthWatcher for the cluster of the client.
+    ///
+    /// By default the cluster is polled every 10 seconds, and indexing queues longer than 1000
+    /// vectors are reported.
+    pub fn new(client: &WeaviateClient) -> ClusterHealthWatcher {
+        ClusterHealthWatcher {
+            client: client.clone(),
+            interval: Duration::from_secs(10),
+            queue_threshold: 1000,
+        }
+    }
+
+    /// Set the time between polls of the cluster.
+    pub fn with_interval(mut self, interval: Duration) -> ClusterHealthWatcher {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the length above which the vector indexing queue of a shard is reported.
+    pub fn with_queue_threshold(mut self, queue_threshold: u64) -> ClusterHealthWatcher {
+        self.queue_threshold = queue_threshold;
+        self
+    }
+
+    /// Start watching the cluster in a new tokio task, polling it immediately.
+    ///
+    /// The task stops when the returned `HealthEvents` is dropped. Must be called from within a
+    /// tokio runtime.
+    pub fn watch(self) -> HealthEvents {
+        let (sender, receiver) = mpsc::channel(64);
+        let handle = tokio::spawn(async move {
+            let mut state = HealthState::default();
+            let mut interval = tokio::time::interval(self.interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                for event in self.poll(&mut state).await {
+                    if sender.send(event).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        HealthEvents { receiver, handle }
+    }
+
+    async fn poll(&self, state: &mut HealthState) -> Vec<HealthEvent> {
+        let mut events = Vec::new();
+        match self.poll_cluster(state, &mut events).await {
+            Some(err) if !state.poll_failed => {
+                state.poll_failed = true;
+                events.insert(0, HealthEvent::PollFailed(err));
+            }
+            Some(_) => {}
+            None if state.poll_failed => {
+                state.poll_failed = false;
+                events.push(HealthEvent::PollRecovered);
+            }
+            None => {}
+        }
+        events
+    }
+
+    /// Poll the cluster, pushing the changes in its health and returning the first error.
+    async fn poll_cluster(
+        &self,
+        state: &mut HealthState,
+        events: &mut Vec<HealthEvent>,
+    ) -> Option<WeaviateError> {
+        let mut error = None;
+        let live = match self.client.is_live().await {
+            Ok(live) => live,
+            Err(err) => {
+                error = Some(err);
+                false
+            }
+        };
+        if state.live != live {
+            state.live = live;
+            events.push(HealthEvent::LivenessChanged { live });
+        }
+        if !live {
+            return error;
+        }
+
+        match self.client.is_ready().await {
+            Ok(ready) if state.ready != ready => {
+                state.ready = ready;
+                events.push(HealthEvent::ReadinessChanged { ready });
+            }
+            Ok(_) => {}
+            Err(err) => error = error.or(Some(err)),
+        }
+
+        match self
+            .client
+            .nodes()
+            .get_nodes_status(None, Some(NodeOutput::Verbose))
+            .await
+        {
+            Ok(nodes) => events.extend(state.update(&nodes, self.queue_threshold)),
+            Err(err) => error = error.or(Some(err)),
+        }
+        error
+    }
+}
+
+/// The stream of `HealthEvent`s of a `ClusterHealthWatcher`.
+///
+/// Dropping the stream stops the watcher.
+#[derive(Debug)]
+pub struct HealthEvents {
+    receiver: mpsc::Receiver<HealthEvent>,
+    handle: JoinHandle<()>,
+}
+
+impl HealthEvents {
+    /// Wait for the next event, without needing a `StreamExt` trait in scope.
+    pub async fn next(&mut self) -> Option<HealthEvent> {
+        self.receiver.recv().await
+    }
+}
+
+impl Stream for HealthEvents {
+    type Item = HealthEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<HealthEvent>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for HealthEvents {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// A shard of a node, identified by the node and shard names.
+type ShardKey = (String, String);
+
+/// The health of the cluster at the last poll, used to only report changes.
+///
+/// The shards are kept in ordered maps from their keys to their classes, so the events of a poll
+/// are reported in a deterministic order.
+#[derive(Debug)]
+struct HealthState {
+    live: bool,
+    ready: bool,
+    poll_failed: bool,
+    unhealthy: BTreeSet<String>,
+    read_only: BTreeMap<ShardKey, String>,
+    queued: BTreeMap<ShardKey, String>,
+    versions: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for HealthState {
+    fn default() -> Self {
+        HealthState {
+            live: true,
+            ready: true,
+            poll_failed: false,
+            unhealthy: BTreeSet::new(),
+            read_only: BTreeMap::new(),
+            queued: BTreeMap::new(),
+            versions: BTreeMap::new(),
+        }
+    }
+}
+
+impl HealthState {
+    /// Update the state from the verbose status of the nodes, returning the changes.
+    fn update(&mut self, nodes: &MultiNodes, queue_threshold: u64) -> Vec<HealthEvent> {
+        let mut events = Vec::new();
+        let mut unhealthy = BTreeSet::new();
+        let mut read_only = BTreeMap::new();
+        let mut queued = BTreeMap::new();
+        let mut versions: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+        for node in &nodes.nodes {
+            let name = node.name.clone().unwrap_or_default();
+            match &node.status {
+                Some(NodeStatus::HEALTHY | NodeStatus::INDEXING) | None => {}
+                Some(status) => {
+                    if !self.unhealthy.contains(&name) {
+                        events.push(HealthEvent::NodeUnhealthy {
+                            node: name.clone(),
+                            status: status.clone(),
+                        });
+                    }
+                    unhealthy.insert(name.clone());
+                }
+            }
+            if let Some(version) = &node.version {
+                versions
+                    .entry(version.clone())
+                    .or_default()
+                    .push(name.clone());
+            }
+            for shard in node.shards.iter().flat_map(|shards| &shards.0) {
+                let key = (name.clone(), shard.name.clone().unwrap_or_default());
+                let class = shard.class.clone().unwrap_or_default();
+                if shard.vector_indexing_status == Some(VectorIndexingStatus::READONLY) {
+                    read_only.insert(key.clone(), class.clone());
+                }
+                if shard.vector_queue_length.unwrap_or(0) > queue_threshold {
+                    queued.insert(key, class);
+                }
+            }
+        }
+
+        for node in self.unhealthy.difference(&unhealthy) {
+            events.push(HealthEvent::NodeHealthy { node: node.clone() });
+        }
+        self.unhealthy = unhealthy;
+
+        for ((node, shard), class) in &read_only {
+            if !self.read_only.contains_key(&(node.clone(), shard.clone())) {
+                events.push(HealthEvent::ShardReadOnly {
+                    node: node.clone(),
+                    class: class.clone(),
+                    shard: shard.clone(),
+                });
+            }
+        }
+        for ((node, shard), class) in &self.read_only {
+            if !read_only.contains_key(&(node.clone(), shard.clone())) {
+                events.push(HealthEvent::ShardWritable {
+                    node: node.clone(),
+                    class: class.clone(),
+                    shard: shard.clone(),
+                });
+            }
+        }
+        self.read_only = read_only;
+
+        for ((node, shard), class) in &queued {
+            if !self.queued.contains_key(&(node.clone(), shard.clone())) {
+                events.push(HealthEvent::IndexingQueueAboveThreshold {
+                    node: node.clone(),
+                    class: class.clone(),
+                    shard: shard.clone(),
+                    length: shard_queue_length(nodes, node, shard),
+                });
+            }
+        }
+        for ((node, shard), class) in &self.queued {
+            if !queued.contains_key(&(node.clone(), shard.clone())) {
+                events.push(HealthEvent::IndexingQueueBelowThreshold {
+                    node: node.clone(),
+                    class: class.clone(),
+                    shard: shard.clone(),
+                    length: shard_queue_length(nodes, node, shard),
+                });
+            }
+        }
+        self.queued = queued;
+
+        for nodes in versions.values_mut() {
+            nodes.sort();
+        }
+        if versions.len() > 1 && versions != self.versions {
+            events.push(HealthEvent::VersionSkew {
+                versions: versions.clone(),
+            });
+        } else if versions.len() <= 1 && self.versions.len() > 1 {
+            if let Some(version) = versions.keys().next() {
+                events.push(HealthEvent::VersionsAligned {
+                    version: version.clone(),
+                });
+            }
+        }
+        self.versions = versions;
+
+        events
+    }
+}
+
+/// Find the indexing queue length of a shard, which is 0 if the shard is no longer reported.
+fn shard_queue_length(nodes: &MultiNodes, node: &str, shard: &str) -> u64 {
+    find_shard(nodes, node, shard)
+        .and_then(|shard| shard.vector_queue_length)
+        .unwrap_or(0)
+}
+
+fn find_shard<'a>(nodes: &'a MultiNodes, node: &str, shard: &str) -> Option<&'a NodeShard> {
+    nodes
+        .nodes
+        .iter()
+        .filter(|n| n.name.as_deref() == Some(node))
+        .flat_map(|n| n.shards.iter().flat_map(|shards| &shards.0))
+        .find(|s| s.name.as_deref() == Some(shard))
+}
+
+/// Options for waiting for a Weaviate server to start, see `WeaviateClient::wait_until_ready`.
+///
+/// By default, the server is checked with a backoff starting at 100 milliseconds and doubling up
+/// to 5 seconds, for at most 60 seconds.
+#[derive(Debug, Clone)]
+pub struct ReadyWaitOptions {
+    pub timeout: Duration,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub cluster_health: bool,
+}
+
+impl ReadyWaitOptions {
+    /// Create new ReadyWaitOptions with the default values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::ReadyWaitOptions;
+    ///
+    /// let options = ReadyWaitOptions::new();
+    /// ```
+    pub fn new() -> ReadyWaitOptions {
+        ReadyWaitOptions {
+            timeout: Duration::from_secs(60),
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            cluster_health: false,
+        }
+    }
+
+    /// Set the maximum time to wait for the server.
+    ///
+    /// If the server is not available in time, `WeaviateError::WaitTimeout` is returned.
+    ///
+    /// # Parameters
+    /// - timeout: the maximum time to wait
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use weaviate_community::ReadyWaitOptions;
+    ///
+    /// let options = ReadyWaitOptions::new().with_timeout(Duration::from_secs(120));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> ReadyWaitOptions {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the time to wait after the first failed check, which doubles after every following
+    /// failed check up to `max_backoff`.
+    ///
+    /// # Parameters
+    /// - initial_backoff: the time to wait after the first failed check
+    /// - max_backoff: the maximum time to wait between checks
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use weaviate_community::ReadyWaitOptions;
+    ///
+    /// let options = ReadyWaitOptions::new()
+    ///     .with_backoff(Duration::from_millis(250), Duration::from_secs(2));
+    /// ```
+    pub fn with_backoff(
+        mut self,
+        initial_backoff: Duration,
+        max_backoff: Duration,
+    ) -> ReadyWaitOptions {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Also wait for all nodes to report `HEALTHY` and all shards of all classes to report
+    /// `READY` when waiting for the server to be ready.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::ReadyWaitOptions;
+    ///
+    /// let options = ReadyWaitOptions::new().with_cluster_health();
+    /// ```
+    pub fn with_cluster_health(mut self) -> ReadyWaitOptions {
+        self.cluster_health = true;
+        self
+    }
+}
+
+impl Default for ReadyWaitOptions {
+    fn default() -> Self {
+        ReadyWaitOptions::new()
+    }
+}
+
+/// The condition to wait for with `wait_until`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Availability {
+    Live,
+    Ready,
+}
+
+/// Wait until the server reaches the availability, checking it with a backoff.
+pub(crate) async fn wait_until(
+    client: &WeaviateClient,
+    availability: Availability,
+    options: &ReadyWaitOptions,
+) -> Result<(), WeaviateError> {
+    let deadline = tokio::time::Instant::now() + options.timeout;
+    let mut backoff = options.initial_backoff;
+    let mut last_reason = None;
+    loop {
+        // The requests have no timeout of their own, so a server that accepts connections but
+        // never responds could otherwise block the wait past its deadline
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        let check = check_availability(client, availability, options);
+        let reason = match tokio::time::timeout(remaining, check).await {
+            Ok(Ok(None)) => return Ok(()),
+            Ok(Ok(Some(reason))) => reason,
+            Ok(Err(err)) => err.to_string(),
+            Err(_) => {
+                return Err(WeaviateError::WaitTimeout {
+                    timeout: options.timeout,
+                    reason: last_reason.unwrap_or_else(|| "server did not respond in time".into()),
+                })
+            }
+        };
+        let now = tokio::time::Instant::now();
+        if now >= deadline {
+            return Err(WeaviateError::WaitTimeout {
+                timeout: options.timeout,
+                reason,
+            });
+        }
+        last_reason = Some(reason);
+        tokio::time::sleep(backoff.min(deadline - now)).await;
+        backoff = (backoff * 2).min(options.max_backoff);
+    }
+}
+
+/// Check the availability of the server once, returning the reason if it is not available.
+async fn check_availability(
+    client: &WeaviateClient,
+    availability: Availability,
+    options: &ReadyWaitOptions,
+) -> Result<Option<String>, WeaviateError> {
+    if availability == Availability::Live {
+        return Ok((!client.is_live().await?).then(|| "server is not live".into()));
+    }
+    if !client.is_ready().await? {
+        return Ok(Some("server is not ready".into()));
+    }
+    if !options.cluster_health {
+        return Ok(None);
+    }
+
+    let nodes = client.nodes().get_nodes_status(None, None).await?;
+    for node in &nodes.nodes {
+        if node.status != Some(NodeStatus::HEALTHY) {
+            return Ok(Some(format!(
+                "node `{}` is {:?}",
+                node.name.as_deref().unwrap_or_default(),
+                node.status
+            )));
+        }
+    }
+    for class in client.schema().get().await?.classes {
+        for shard in client.schema().get_shards(&class.class).await?.shards {
+            if shard.status != ShardStatus::READY {
+                return Ok(Some(format!(
+                    "shard `{}` of class `{}` is {}",
+                    shard.name,
+                    class.class,
+                    shard.status.value()
+                )));
+            }
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_nodes(status: &str, version: &str, indexing: &str, queue: u64) -> MultiNodes {
+        serde_json::from_value(serde_json::json!({
+            "nodes": [
+                {
+                    "name": "weaviate-0",
+                    "status": "HEALTHY",
+                    "version": "1.24.1",
+                    "shards": []
+                },
+                {
+                    "name": "weaviate-1",
+                    "status": status,
+                    "version": version,
+                    "shards": [{
+                        "class": "Article",
+                        "name": "abc",
+                        "vectorIndexingStatus": indexing,
+                        "vectorQueueLength": queue
+                    }]
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_health_state_reports_changes() {
+        let mut state = HealthState::default();
+        assert!(state
+            .update(&test_nodes("HEALTHY", "1.24.1", "READY", 0), 100)
+            .is_empty());
+
+        let events = state.update(&test_nodes("UNHEALTHY", "1.24.2", "READONLY", 500), 100);
+        assert_eq!(events.len(), 4);
+        assert!(matches!(
+            &events[0],
+            HealthEvent::NodeUnhealthy { node, status: NodeStatus::UNHEALTHY } if node == "weaviate-1"
+        ));
+        assert!(matches!(
+            &events[1],
+            HealthEvent::ShardReadOnly { class, shard, .. } if class == "Article" && shard == "abc"
+        ));
+        assert!(matches!(
+            &events[2],
+            HealthEvent::IndexingQueueAboveThreshold { length: 500, .. }
+        ));
+        assert!(matches!(
+            &events[3],
+            HealthEvent::VersionSkew { versions } if versions.len() == 2
+        ));
+
+        // Persisting problems are not reported again
+        assert!(state
+            .update(&test_nodes("UNHEALTHY", "1.24.2", "READONLY", 400), 100)
+            .is_empty());
+
+        let events = state.update(&test_nodes("HEALTHY", "1.24.1", "READY", 0), 100);
+        assert_eq!(events.len(), 4);
+        assert!(matches!(&events[0], HealthEvent::NodeHealthy { .. }));
+        assert!(
+            matches!(&events[1], HealthEvent::ShardWritable { class, .. } if class == "Article")
+        );
+        assert!(matches!(
+            &events[2],
+            HealthEvent::IndexingQueueBelowThreshold { length: 0, .. }
+        ));
+        assert!(matches!(
+            &events[3],
+            HealthEvent::VersionsAligned { version } if version == "1.24.1"
+        ));
+    }
+
+    #[test]
+    fn test_health_state_reports_version_changes() {
+        let nodes = |versions: &[&str]| -> MultiNodes {
+            let nodes: Vec<_> = versions
+                .iter()
+                .enumerate()
+                .map(|(i, version)| {
+                    serde_json::json!({
+                        "name": format!("weaviate-{i}"),
+                        "status": "HEALTHY",
+                        "version": version
+                    })
+                })
+                .collect();
+            serde_json::from_value(serde_json::json!({ "nodes": nodes })).unwrap()
+        };
+        let skews = |events: Vec<HealthEvent>| -> Vec<Vec<String>> {
+            events
+                .into_iter()
+                .map(|event| match event {
+                    HealthEvent::VersionSkew { versions } => versions.into_keys().collect(),
+                    event => panic!("unexpected event {event:?}"),
+                })
+                .collect()
+        };
+        let mut state = HealthState::default();
+        assert!(state
+            .update(&nodes(&["1.24.1", "1.24.1", "1.24.1"]), 100)
+            .is_empty());
+        assert_eq!(
+            skews(state.update(&nodes(&["1.24.1", "1.24.2", "1.24.1"]), 100)),
+            vec![vec!["1.24.1", "1.24.2"]]
+        );
+        // The same skew is not reported again
+        assert!(state
+            .update(&nodes(&["1.24.1", "1.24.2", "1.24.1"]), 100)
+            .is_empty());
+        // A node moving to another version is reported, as is a third version
+        assert_eq!(
+            skews(state.update(&nodes(&["1.24.1", "1.24.2", "1.24.2"]), 100)),
+            vec![vec!["1.24.1", "1.24.2"]]
+        );
+        assert_eq!(
+            skews(state.update(&nodes(&["1.24.3", "1.24.2", "1.24.2"]), 100)),
+            vec![vec!["1.24.2", "1.24.3"]]
+        );
+        assert_eq!(
+            skews(state.update(&nodes(&["1.24.3", "1.24.2", "1.24.1"]), 100)),
+            vec![vec!["1.24.1", "1.24.2", "1.24.3"]]
+        );
+        let events = state.update(&nodes(&["1.24.3", "1.24.3", "1.24.3"]), 100);
+        assert!(matches!(
+            events.as_slice(),
+            [HealthEvent::VersionsAligned { version }] if version == "1.24.3"
+        ));
+    }
+
+    #[test]
+    fn test_health_state_orders_shards() {
+        let nodes: MultiNodes = serde_json::from_value(serde_json::json!({
+            "nodes": [
+                {
+                    "name": "weaviate-1",
+                    "status": "HEALTHY",
+                    "shards": [
+                        {"class": "Article", "name": "zzz", "vectorIndexingStatus": "READONLY"},
+                        {"class": "Author", "name": "aaa", "vectorIndexingStatus": "READONLY"}
+                    ]
+                },
+                {
+                    "name": "weaviate-0",
+                    "status": "HEALTHY",
+                    "shards": [
+                        {"class": "Article", "name": "mmm", "vectorIndexingStatus": "READONLY"}
+                    ]
+                }
+            ]
+        }))
+        .unwrap();
+        let shards = |events: Vec<HealthEvent>| -> Vec<String> {
+            events
+                .into_iter()
+                .map(|event| match event {
+                    HealthEvent::ShardReadOnly { node, shard, .. }
+                    | HealthEvent::ShardWritable { node, shard, .. } => format!("{node}/{shard}"),
+                    event => panic!("unexpected event {event:?}"),
+                })
+                .collect()
+        };
+        let mut state = HealthState::default();
+        let expected = vec!["weaviate-0/mmm", "weaviate-1/aaa", "weaviate-1/zzz"];
+        assert_eq!(shards(state.update(&nodes, 100)), expected);
+        assert_eq!(
+            shards(state.update(&MultiNodes { nodes: Vec::new() }, 100)),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_poll_reports_failure_transitions() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let client = WeaviateClient::builder(format!("http://{}", mock_server.host_with_port()))
+            .build()
+            .unwrap();
+        let _live = mock_server
+            .mock("GET", "/v1/.well-known/live")
+            .with_status(200)
+            .create();
+        let _ready = mock_server
+            .mock("GET", "/v1/.well-known/ready")
+            .with_status(200)
+            .create();
+        let failing = mock_server
+            .mock("GET", "/v1/nodes/")
+            .match_query(mockito::Matcher::Any)
+            .with_status(500)
+            .create();
+
+        let watcher = ClusterHealthWatcher::new(&client);
+        let mut state = HealthState::default();
+        let events = watcher.poll(&mut state).await;
+        assert!(matches!(events.as_slice(), [HealthEvent::PollFailed(_)]));
+        // A failure that persists is not reported again
+        assert!(watcher.poll(&mut state).await.is_empty());
+
+        failing.remove();
+        let _nodes = mock_server
+            .mock("GET", "/v1/nodes/")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_body(r#"{"nodes": []}"#)
+            .create();
+        let events = watcher.poll(&mut state).await;
+        assert!(matches!(events.as_slice(), [HealthEvent::PollRecovered]));
+        assert!(watcher.poll(&mut state).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_watch() {
+        let mut mock_server = mockito::Server::new_async().await;
+        let client = WeaviateClient::builder(format!("http://{}", mock_server.host_with_port()))
+            .build()
+            .unwrap();
+        let _live = mock_server
+            .mock("GET", "/v1/.well-known/live")
+            .with_status(200)
+            .create();
+        let _ready = mock_server
+            .mock("GET", "/v1/.well-known/ready")
+            .with_status(503)
+            .create();
+        let nodes =
+            serde_json::to_string(&test_nodes("UNAVAILABLE", "1.24.1", "READY", 0)).unwrap();
+        let _nodes = mock_server
+            .mock("GET", "/v1/nodes/")
+            .match_query(mockito::Matcher::UrlEncoded(
+                "output".into(),
+                "verbose".into(),
+            ))
+            .with_status(200)
+            .with_body(nodes)
+            .create();
+
+        let mut events = ClusterHealthWatcher::new(&client)
+            .with_interval(Duration::from_millis(10))
+            .watch();
+        assert!(matches!(
+            events.next().await,
+            Some(HealthEvent::ReadinessChanged { ready: false })
+        ));
+        assert!(matches!(
+            events.next().await,
+            Some(HealthEvent::NodeUnhealthy {
+                status: NodeStatus::UNAVAILABLE,
+                ..
+            })
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::error::WeaviateError;
 
 pub(crate) trait ResponseExt {
@@ -29,3 +31,118 @@ impl ResponseExt for reqwest::Response {
         })
     }
 }
+
+/// Convert days since the epoch to a (year, month, day) date, for dates after the epoch.
+pub(crate) fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let doe = days % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Convert a (year, month, day) date to days since the epoch, for dates after the epoch.
+pub(crate) fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let yoe = year % 400;
+    let mp = if month > 2 { month - 3 } else { month + 9 };
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Parse an RFC 3339 timestamp, such as `2023-11-01T12:00:00.123Z`, into the time since the epoch.
+///
+/// Timestamps before the epoch, such as the `0001-01-01T00:00:00Z` zero time that Weaviate uses
+/// for unset times, are `None`.
+pub(crate) fn parse_rfc3339(timestamp: &str) -> Option<Duration> {
+    let bytes = timestamp.as_bytes();
+    if bytes.len() < 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| -> Option<u64> {
+        let digits = timestamp.get(range)?;
+        if !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    };
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &timestamp[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction.bytes().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        let digits: String = fraction[..len]
+            .chars()
+            .chain("000000000".chars())
+            .take(9)
+            .collect();
+        nanos = digits.parse().ok()?;
+        rest = &fraction[len..];
+    }
+    let offset: i64 = match rest {
+        "Z" | "z" => 0,
+        _ if rest.len() == 6 && rest.as_bytes()[3] == b':' => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let hours = number(timestamp.len() - 5..timestamp.len() - 3)?;
+            let minutes = number(timestamp.len() - 2..timestamp.len())?;
+            sign * (hours * 3600 + minutes * 60) as i64
+        }
+        _ => return None,
+    };
+
+    if year < 1970 {
+        return None;
+    }
+    let secs = (days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second)
+        as i64
+        - offset;
+    if secs < 0 {
+        return None;
+    }
+    Some(Duration::new(secs as u64, nanos))
+}
+
+/// Format the time since the epoch as an RFC 3339 timestamp in UTC with milliseconds, such as
+/// `2023-11-01T12:00:00.123Z`.
+pub(crate) fn format_rfc3339(time: Duration) -> String {
+    let secs = time.as_secs();
+    let (year, month, day) = civil_from_days(secs / 86400);
+    let secs = secs % 86400;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60,
+        time.subsec_millis()
+    )
+}
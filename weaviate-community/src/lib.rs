@@ -2,9 +2,21 @@
 //!
 //! Community client for handling Weaviate vector database transactions written in Rust, for Rust.
 //! More information on Weaviate can be found on the official Weaviate webpage.
+mod cache;
 mod client;
 mod endpoints;
+mod health;
+mod scheduler;
+pub use cache::QueryCache;
 pub use client::{WeaviateClient, WeaviateClientBuilder};
+pub use health::{ClusterHealthWatcher, HealthEvent, HealthEvents, ReadyWaitOptions};
+pub use scheduler::{BackupRunReport, BackupScheduler, BackupSchedulerHandle};
+
+/// Build a `RawQuery` from a Weaviate GraphQL query that is checked at compile time.
+///
+/// Requires the `macros` feature.
+#[cfg(feature = "macros")]
+pub use weaviate_community_macros::weaviate_query;
 
 pub mod error;
 
@@ -1,22 +1,50 @@
 /// All backup associated type components
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
-/// Strict definitions of the different backends available for backups.
+/// The backends available for backups.
+///
+/// Weaviate supports the S3, GCS, AZURE, and FILESYSTEM backup modules. Any other backup module,
+/// such as a custom module, can be used with `BackupBackends::Custom`, holding the name of the
+/// backend. Backends that are not known are also deserialized into `BackupBackends::Custom`.
 ///
-/// Weaviate supports S3, GCS, AZURE, and FILESYSTEM shard status.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+/// Backends are compared by their names, so `BackupBackends::Custom("s3".into())` is equal to
+/// `BackupBackends::S3`.
+#[derive(Debug, Clone)]
 pub enum BackupBackends {
-    #[serde(rename = "s3")]
     S3,
-    #[serde(rename = "gcs")]
     GCS,
-    #[serde(rename = "azure")]
     AZURE,
-    #[serde(rename = "filesystem")]
     FILESYSTEM,
+    Custom(String),
 }
 
 impl BackupBackends {
+    /// Create a BackupBackends for a backend that is not known by this crate.
+    ///
+    /// The names of the known backends give their own variant instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupBackends;
+    ///
+    /// let backend = BackupBackends::custom("backup-minio");
+    /// assert_eq!(backend.value(), "backup-minio");
+    /// assert_eq!(BackupBackends::custom("s3"), BackupBackends::S3);
+    /// ```
+    pub fn custom(name: &str) -> BackupBackends {
+        match name {
+            "s3" => BackupBackends::S3,
+            "gcs" => BackupBackends::GCS,
+            "azure" => BackupBackends::AZURE,
+            "filesystem" => BackupBackends::FILESYSTEM,
+            name => BackupBackends::Custom(name.into()),
+        }
+    }
+
     /// Retrieve the string value associated to the BackupBackends enum types.
     ///
     /// # Example
@@ -31,10 +59,44 @@ impl BackupBackends {
             BackupBackends::GCS => "gcs",
             BackupBackends::AZURE => "azure",
             BackupBackends::FILESYSTEM => "filesystem",
+            BackupBackends::Custom(name) => name,
         }
     }
 }
 
+impl PartialEq for BackupBackends {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+
+impl Eq for BackupBackends {}
+
+impl std::hash::Hash for BackupBackends {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value().hash(state);
+    }
+}
+
+impl std::fmt::Display for BackupBackends {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl Serialize for BackupBackends {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.value())
+    }
+}
+
+impl<'de> Deserialize<'de> for BackupBackends {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(BackupBackends::custom(&name))
+    }
+}
+
 /// BackupCreateRequest struct defining the options for the json payload required to create a new
 /// backup.
 #[derive(Serialize, Deserialize, Debug)]
@@ -42,6 +104,9 @@ pub struct BackupCreateRequest {
     pub id: String,
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub config: Option<BackupConfig>,
 }
 
 impl BackupCreateRequest {
@@ -68,6 +133,7 @@ pub struct BackupCreateRequestBuilder {
     pub id: String,
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    pub config: Option<BackupConfig>,
 }
 
 impl BackupCreateRequestBuilder {
@@ -89,6 +155,7 @@ impl BackupCreateRequestBuilder {
             id: id.into(),
             include: None,
             exclude: None,
+            config: None,
         }
     }
 
@@ -126,6 +193,28 @@ impl BackupCreateRequestBuilder {
         self
     }
 
+    /// Add a value to the optional `config` value of the BackupCreateRequest.
+    ///
+    /// # Parameters
+    /// - config: the configuration of the backup
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::{
+    ///     BackupConfig, BackupCreateRequestBuilder, CompressionLevel
+    /// };
+    ///
+    /// let builder = BackupCreateRequestBuilder::new("my-backup").with_config(
+    ///     BackupConfig::new()
+    ///         .with_cpu_percentage(40)
+    ///         .with_compression_level(CompressionLevel::BestSpeed)
+    /// );
+    /// ```
+    pub fn with_config(mut self, config: BackupConfig) -> BackupCreateRequestBuilder {
+        self.config = Some(config);
+        self
+    }
+
     /// Build the BackupCreateRequest from the BackupCreateRequestBuilder
     ///
     /// # Example
@@ -147,6 +236,7 @@ impl BackupCreateRequestBuilder {
             id: self.id,
             include: self.include,
             exclude: self.exclude,
+            config: self.config,
         }
     }
 }
@@ -157,6 +247,16 @@ impl BackupCreateRequestBuilder {
 pub struct BackupRestoreRequest {
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub config: Option<RestoreConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub node_mapping: Option<HashMap<String, String>>,
+    #[serde(rename = "overwriteAlias")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub overwrite_alias: Option<bool>,
 }
 
 impl BackupRestoreRequest {
@@ -180,6 +280,9 @@ impl BackupRestoreRequest {
 pub struct BackupRestoreRequestBuilder {
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
+    pub config: Option<RestoreConfig>,
+    pub node_mapping: Option<HashMap<String, String>>,
+    pub overwrite_alias: Option<bool>,
 }
 
 impl BackupRestoreRequestBuilder {
@@ -197,6 +300,9 @@ impl BackupRestoreRequestBuilder {
         BackupRestoreRequestBuilder {
             include: None,
             exclude: None,
+            config: None,
+            node_mapping: None,
+            overwrite_alias: None,
         }
     }
 
@@ -234,6 +340,66 @@ impl BackupRestoreRequestBuilder {
         self
     }
 
+    /// Add a value to the optional `config` value of the BackupRestoreRequest.
+    ///
+    /// # Parameters
+    /// - config: the configuration of the restore
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::{BackupRestoreRequestBuilder, RestoreConfig};
+    ///
+    /// let builder = BackupRestoreRequestBuilder::new()
+    ///     .with_config(RestoreConfig::new().with_cpu_percentage(40));
+    /// ```
+    pub fn with_config(mut self, config: RestoreConfig) -> BackupRestoreRequestBuilder {
+        self.config = Some(config);
+        self
+    }
+
+    /// Add a value to the optional `node_mapping` value of the BackupRestoreRequest.
+    ///
+    /// This is used to restore a backup to a cluster whose node names differ from the cluster
+    /// that created it.
+    ///
+    /// # Parameters
+    /// - node_mapping: pairs of the node name in the backup and the node name to restore to
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupRestoreRequestBuilder;
+    ///
+    /// let builder = BackupRestoreRequestBuilder::new()
+    ///     .with_node_mapping(vec![("node1", "weaviate-0"), ("node2", "weaviate-1")]);
+    /// ```
+    pub fn with_node_mapping(
+        mut self,
+        node_mapping: Vec<(&str, &str)>,
+    ) -> BackupRestoreRequestBuilder {
+        let node_mapping = node_mapping
+            .iter()
+            .map(|(from, to)| (from.to_string(), to.to_string()))
+            .collect();
+        self.node_mapping = Some(node_mapping);
+        self
+    }
+
+    /// Add a value to the optional `overwrite_alias` value of the BackupRestoreRequest.
+    ///
+    /// # Parameters
+    /// - overwrite_alias: whether to overwrite existing aliases with the aliases in the backup
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupRestoreRequestBuilder;
+    ///
+    /// let builder = BackupRestoreRequestBuilder::new().with_overwrite_alias(true);
+    /// ```
+    pub fn with_overwrite_alias(mut self, overwrite_alias: bool) -> BackupRestoreRequestBuilder {
+        self.overwrite_alias = Some(overwrite_alias);
+        self
+    }
+
     /// Build the BackupRestoreRequest from the BackupRestoreRequestBuilder
     ///
     /// # Example
@@ -254,29 +420,304 @@ impl BackupRestoreRequestBuilder {
         BackupRestoreRequest {
             include: self.include,
             exclude: self.exclude,
+            config: self.config,
+            node_mapping: self.node_mapping,
+            overwrite_alias: self.overwrite_alias,
         }
     }
 }
 
-/// Strict definitions of the different backup status' available for backups.
+/// The compression level of a backup.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum CompressionLevel {
+    DefaultCompression,
+    BestSpeed,
+    BestCompression,
+}
+
+/// Whether to restore the roles or users in a backup.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum RbacRestoreOption {
+    #[serde(rename = "noRestore")]
+    NoRestore,
+    #[serde(rename = "all")]
+    All,
+}
+
+/// The `config` options of a BackupCreateRequest.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct BackupConfig {
+    #[serde(rename = "CPUPercentage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cpu_percentage: Option<u8>,
+    #[serde(rename = "ChunkSize")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub chunk_size: Option<u32>,
+    #[serde(rename = "CompressionLevel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub compression_level: Option<CompressionLevel>,
+    #[serde(rename = "Bucket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub bucket: Option<String>,
+    #[serde(rename = "Path")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+impl BackupConfig {
+    /// Create a new BackupConfig with all options unset, leaving them to the server defaults.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupConfig;
+    ///
+    /// let config = BackupConfig::new();
+    /// ```
+    pub fn new() -> BackupConfig {
+        BackupConfig::default()
+    }
+
+    /// Set the percentage of the CPU to use for the backup, between 1 and 80.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupConfig;
+    ///
+    /// let config = BackupConfig::new().with_cpu_percentage(40);
+    /// ```
+    pub fn with_cpu_percentage(mut self, cpu_percentage: u8) -> BackupConfig {
+        self.cpu_percentage = Some(cpu_percentage);
+        self
+    }
+
+    /// Set the size of the chunks of the backup, in MB between 2 and 512.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupConfig;
+    ///
+    /// let config = BackupConfig::new().with_chunk_size(256);
+    /// ```
+    pub fn with_chunk_size(mut self, chunk_size: u32) -> BackupConfig {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Set the compression level of the backup.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::{BackupConfig, CompressionLevel};
+    ///
+    /// let config = BackupConfig::new().with_compression_level(CompressionLevel::BestCompression);
+    /// ```
+    pub fn with_compression_level(mut self, compression_level: CompressionLevel) -> BackupConfig {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Set the bucket to store the backup in, overriding the bucket of the backend module.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupConfig;
+    ///
+    /// let config = BackupConfig::new().with_bucket("nightly-backups");
+    /// ```
+    pub fn with_bucket(mut self, bucket: &str) -> BackupConfig {
+        self.bucket = Some(bucket.into());
+        self
+    }
+
+    /// Set the path to store the backup at, overriding the path of the backend module.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupConfig;
+    ///
+    /// let config = BackupConfig::new().with_path("weaviate/2024");
+    /// ```
+    pub fn with_path(mut self, path: &str) -> BackupConfig {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+/// The `config` options of a BackupRestoreRequest.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct RestoreConfig {
+    #[serde(rename = "CPUPercentage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub cpu_percentage: Option<u8>,
+    #[serde(rename = "Bucket")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub bucket: Option<String>,
+    #[serde(rename = "Path")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(rename = "rolesOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub roles_options: Option<RbacRestoreOption>,
+    #[serde(rename = "usersOptions")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub users_options: Option<RbacRestoreOption>,
+}
+
+impl RestoreConfig {
+    /// Create a new RestoreConfig with all options unset, leaving them to the server defaults.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::RestoreConfig;
+    ///
+    /// let config = RestoreConfig::new();
+    /// ```
+    pub fn new() -> RestoreConfig {
+        RestoreConfig::default()
+    }
+
+    /// Set the percentage of the CPU to use for the restore, between 1 and 80.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::RestoreConfig;
+    ///
+    /// let config = RestoreConfig::new().with_cpu_percentage(40);
+    /// ```
+    pub fn with_cpu_percentage(mut self, cpu_percentage: u8) -> RestoreConfig {
+        self.cpu_percentage = Some(cpu_percentage);
+        self
+    }
+
+    /// Set the bucket to restore the backup from, overriding the bucket of the backend module.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::RestoreConfig;
+    ///
+    /// let config = RestoreConfig::new().with_bucket("nightly-backups");
+    /// ```
+    pub fn with_bucket(mut self, bucket: &str) -> RestoreConfig {
+        self.bucket = Some(bucket.into());
+        self
+    }
+
+    /// Set the path to restore the backup from, overriding the path of the backend module.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::RestoreConfig;
+    ///
+    /// let config = RestoreConfig::new().with_path("weaviate/2024");
+    /// ```
+    pub fn with_path(mut self, path: &str) -> RestoreConfig {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set whether to restore the roles in the backup.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::{RbacRestoreOption, RestoreConfig};
+    ///
+    /// let config = RestoreConfig::new().with_roles_options(RbacRestoreOption::All);
+    /// ```
+    pub fn with_roles_options(mut self, roles_options: RbacRestoreOption) -> RestoreConfig {
+        self.roles_options = Some(roles_options);
+        self
+    }
+
+    /// Set whether to restore the users in the backup.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::{RbacRestoreOption, RestoreConfig};
+    ///
+    /// let config = RestoreConfig::new().with_users_options(RbacRestoreOption::NoRestore);
+    /// ```
+    pub fn with_users_options(mut self, users_options: RbacRestoreOption) -> RestoreConfig {
+        self.users_options = Some(users_options);
+        self
+    }
+}
+
+/// The different statuses of a backup or restore.
+///
+/// Statuses that are not known by this crate are deserialized into `BackupStatus::Unknown`.
 ///
-/// Weaviate supports STARTED, SUCCESS, FAILED, TRANSFERRING, and TRANSFERRED.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// This shouldn't be something you create yourself, as it is returned by the appropriate
+/// endpoint when deserialized.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BackupStatus {
     STARTED,
-    SUCCESS,
-    FAILED,
     TRANSFERRING,
     TRANSFERRED,
+    FINALIZING,
+    SUCCESS,
+    FAILED,
+    CANCELLING,
+    CANCELED,
+    Unknown(String),
+}
+
+impl BackupStatus {
+    /// Retrieve the string value associated to the BackupStatus enum types.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupStatus;
+    ///
+    /// let success = BackupStatus::SUCCESS.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            BackupStatus::STARTED => "STARTED",
+            BackupStatus::TRANSFERRING => "TRANSFERRING",
+            BackupStatus::TRANSFERRED => "TRANSFERRED",
+            BackupStatus::FINALIZING => "FINALIZING",
+            BackupStatus::SUCCESS => "SUCCESS",
+            BackupStatus::FAILED => "FAILED",
+            BackupStatus::CANCELLING => "CANCELLING",
+            BackupStatus::CANCELED => "CANCELED",
+            BackupStatus::Unknown(status) => status,
+        }
+    }
+
+    fn from_value(status: String) -> BackupStatus {
+        match status.as_str() {
+            "STARTED" => BackupStatus::STARTED,
+            "TRANSFERRING" => BackupStatus::TRANSFERRING,
+            "TRANSFERRED" => BackupStatus::TRANSFERRED,
+            "FINALIZING" => BackupStatus::FINALIZING,
+            "SUCCESS" => BackupStatus::SUCCESS,
+            "FAILED" => BackupStatus::FAILED,
+            "CANCELLING" => BackupStatus::CANCELLING,
+            "CANCELED" => BackupStatus::CANCELED,
+            _ => BackupStatus::Unknown(status),
+        }
+    }
 }
 
+impl_status_serde!(BackupStatus);
+
 /// The general status response for backup status.
 ///
 /// You shouldn't need to ever create this struct - it is just what the response from the backup
 /// endpoints is deserialized into.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct BackupStatusResponse {
-    pub backend: String,
+    pub backend: BackupBackends,
     pub id: String,
     pub path: Option<String>,
     pub status: BackupStatus,
@@ -294,3 +735,440 @@ pub struct BackupResponse {
     pub path: String,
     pub status: BackupStatus,
 }
+
+/// A single backup on a backend, as returned when listing the backups.
+///
+/// You shouldn't need to ever create this struct - it is just what the response from the backup
+/// list endpoint is deserialized into.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupListItem {
+    pub id: String,
+    #[serde(default)]
+    pub classes: Vec<String>,
+    pub status: BackupStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub completed_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub size: Option<f64>,
+}
+
+/// When a `BackupScheduler` creates backups.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BackupSchedule {
+    /// Create a backup every interval, starting one interval after the scheduler is started.
+    ///
+    /// If a backup takes longer than the interval, the missed backups are skipped.
+    Every(Duration),
+    /// Create a backup every day at the given time in UTC, with the hour in `0..24` and the
+    /// minute in `0..60`.
+    Daily { hour: u32, minute: u32 },
+}
+
+impl BackupSchedule {
+    /// Get the time to wait from `now` until the next daily backup.
+    ///
+    /// Returns `None` for `BackupSchedule::Every`, which does not depend on the time of day.
+    pub(crate) fn until_daily(&self, now: Duration) -> Option<Duration> {
+        const DAY: u128 = 24 * 60 * 60 * 1000;
+        let BackupSchedule::Daily { hour, minute } = self else {
+            return None;
+        };
+        let target = u128::from(hour * 60 + minute) * 60 * 1000;
+        let into_day = now.as_millis() % DAY;
+        let delay = (target + DAY - into_day) % DAY;
+        let delay = if delay == 0 { DAY } else { delay };
+        Some(Duration::from_millis(delay as u64))
+    }
+}
+
+/// Which backups a `BackupScheduler` reports as expired after each successful backup.
+///
+/// Only the successful backups created by the scheduler are considered, and the backup that was
+/// just created never expires. A backup expires if it is not one of the `keep_last` most recent
+/// backups, or if it is older than `max_age`. By default no backups expire.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BackupRetention {
+    pub keep_last: Option<usize>,
+    pub max_age: Option<Duration>,
+}
+
+impl BackupRetention {
+    /// Create a new BackupRetention that keeps every backup.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupRetention;
+    ///
+    /// let retention = BackupRetention::new();
+    /// ```
+    pub fn new() -> BackupRetention {
+        BackupRetention::default()
+    }
+
+    /// Keep only the given number of most recent backups.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupRetention;
+    ///
+    /// let retention = BackupRetention::new().with_keep_last(7);
+    /// ```
+    pub fn with_keep_last(mut self, keep_last: usize) -> BackupRetention {
+        self.keep_last = Some(keep_last);
+        self
+    }
+
+    /// Expire the backups older than the given age.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use weaviate_community::models::backups::BackupRetention;
+    ///
+    /// let retention = BackupRetention::new().with_max_age(Duration::from_secs(30 * 24 * 60 * 60));
+    /// ```
+    pub fn with_max_age(mut self, max_age: Duration) -> BackupRetention {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Check if the retention expires any backups.
+    pub(crate) fn is_keep_all(&self) -> bool {
+        self.keep_last.is_none() && self.max_age.is_none()
+    }
+
+    /// Select the expired backups, from the ids and creation times of the backups.
+    ///
+    /// `current` is the id of the backup that was just created, which is always kept.
+    pub(crate) fn expired(
+        &self,
+        mut backups: Vec<(Duration, String)>,
+        now: Duration,
+        current: &str,
+    ) -> Vec<String> {
+        backups.sort_by(|a, b| b.cmp(a));
+        backups
+            .into_iter()
+            .enumerate()
+            .filter(|(i, (created, id))| {
+                id != current
+                    && (self.keep_last.is_some_and(|keep_last| *i >= keep_last)
+                        || self
+                            .max_age
+                            .is_some_and(|max_age| now.saturating_sub(*created) > max_age))
+            })
+            .map(|(_, (_, id))| id)
+            .collect()
+    }
+}
+
+/// The callback type for the progress of a backup, see `BackupWaitOptions::with_progress`.
+type BackupProgress = Arc<dyn Fn(&BackupStatusResponse) + Send + Sync>;
+
+/// Options for waiting for a backup or restore to complete.
+///
+/// By default, the status is polled every second with no timeout.
+#[derive(Clone)]
+pub struct BackupWaitOptions {
+    pub poll_interval: Duration,
+    pub timeout: Option<Duration>,
+    on_progress: Option<BackupProgress>,
+}
+
+impl BackupWaitOptions {
+    /// Create new BackupWaitOptions with the default values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupWaitOptions;
+    ///
+    /// let options = BackupWaitOptions::new();
+    /// ```
+    pub fn new() -> BackupWaitOptions {
+        BackupWaitOptions {
+            poll_interval: Duration::from_secs(1),
+            timeout: None,
+            on_progress: None,
+        }
+    }
+
+    /// Set the time to wait between requests for the status of the backup.
+    ///
+    /// # Parameters
+    /// - poll_interval: the time to wait between requests
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use weaviate_community::models::backups::BackupWaitOptions;
+    ///
+    /// let options = BackupWaitOptions::new().with_poll_interval(Duration::from_millis(500));
+    /// ```
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> BackupWaitOptions {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set the maximum time to wait for the backup to complete.
+    ///
+    /// If the backup has not completed in time, `WeaviateError::BackupTimeout` is returned. The
+    /// backup itself is not cancelled.
+    ///
+    /// # Parameters
+    /// - timeout: the maximum time to wait
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use weaviate_community::models::backups::BackupWaitOptions;
+    ///
+    /// let options = BackupWaitOptions::new().with_timeout(Duration::from_secs(600));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> BackupWaitOptions {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set a callback that is called with the status of the backup every time it changes,
+    /// starting with the first status received.
+    ///
+    /// # Parameters
+    /// - on_progress: the callback to call
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::backups::BackupWaitOptions;
+    ///
+    /// let options = BackupWaitOptions::new().with_progress(|status| {
+    ///     println!("backup {} is {:?}", status.id, status.status);
+    /// });
+    /// ```
+    pub fn with_progress<F>(mut self, on_progress: F) -> BackupWaitOptions
+    where
+        F: Fn(&BackupStatusResponse) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+
+    /// Report the status of a backup to the progress callback, if there is one.
+    pub(crate) fn report(&self, status: &BackupStatusResponse) {
+        if let Some(on_progress) = &self.on_progress {
+            on_progress(status);
+        }
+    }
+}
+
+impl Default for BackupWaitOptions {
+    fn default() -> Self {
+        BackupWaitOptions::new()
+    }
+}
+
+impl std::fmt::Debug for BackupWaitOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackupWaitOptions")
+            .field("poll_interval", &self.poll_interval)
+            .field("timeout", &self.timeout)
+            .field("on_progress", &self.on_progress.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_backends_serde() {
+        for (backend, name) in [
+            (BackupBackends::S3, "s3"),
+            (BackupBackends::GCS, "gcs"),
+            (BackupBackends::AZURE, "azure"),
+            (BackupBackends::FILESYSTEM, "filesystem"),
+            (
+                BackupBackends::Custom("backup-minio".into()),
+                "backup-minio",
+            ),
+        ] {
+            assert_eq!(serde_json::to_value(&backend).unwrap(), name);
+            assert_eq!(
+                serde_json::from_value::<BackupBackends>(name.into()).unwrap(),
+                backend
+            );
+        }
+    }
+
+    #[test]
+    fn test_backup_status_serde() {
+        for (status, name) in [
+            (BackupStatus::FINALIZING, "FINALIZING"),
+            (BackupStatus::CANCELLING, "CANCELLING"),
+            (BackupStatus::Unknown("REPLICATING".into()), "REPLICATING"),
+        ] {
+            assert_eq!(serde_json::to_value(&status).unwrap(), name);
+            assert_eq!(
+                serde_json::from_value::<BackupStatus>(name.into()).unwrap(),
+                status
+            );
+        }
+    }
+
+    #[test]
+    fn test_backup_backends_eq() {
+        use std::collections::HashSet;
+
+        assert_eq!(BackupBackends::Custom("s3".into()), BackupBackends::S3);
+        assert_ne!(BackupBackends::Custom("s4".into()), BackupBackends::S3);
+        let backends: HashSet<_> = [BackupBackends::Custom("gcs".into()), BackupBackends::GCS]
+            .into_iter()
+            .collect();
+        assert_eq!(backends.len(), 1);
+    }
+
+    #[test]
+    fn test_backup_response_unknown_backend() {
+        let res: BackupResponse = serde_json::from_value(serde_json::json!({
+            "backend": "backup-minio",
+            "classes": ["Article"],
+            "id": "my-backup",
+            "path": "s3://bucket/my-backup",
+            "status": "STARTED"
+        }))
+        .unwrap();
+        assert_eq!(res.backend, BackupBackends::custom("backup-minio"));
+    }
+
+    #[test]
+    fn test_backup_schedule_daily() {
+        let schedule = BackupSchedule::Daily {
+            hour: 2,
+            minute: 30,
+        };
+        let midnight = Duration::from_secs(19_000 * 24 * 60 * 60);
+        assert_eq!(
+            schedule.until_daily(midnight),
+            Some(Duration::from_secs(150 * 60))
+        );
+        assert_eq!(
+            schedule.until_daily(midnight + Duration::from_secs(150 * 60)),
+            Some(Duration::from_secs(24 * 60 * 60))
+        );
+        assert_eq!(
+            schedule.until_daily(midnight + Duration::from_secs(3 * 60 * 60)),
+            Some(Duration::from_secs(23 * 60 * 60 + 30 * 60))
+        );
+        assert!(BackupSchedule::Every(Duration::from_secs(60))
+            .until_daily(midnight)
+            .is_none());
+    }
+
+    #[test]
+    fn test_backup_retention_expired() {
+        let day = Duration::from_secs(24 * 60 * 60);
+        let backups = vec![
+            (day, "b-1".to_string()),
+            (day * 3, "b-3".to_string()),
+            (day * 2, "b-2".to_string()),
+            (day * 4, "b-4".to_string()),
+        ];
+        let now = day * 4;
+        assert!(BackupRetention::new()
+            .expired(backups.clone(), now, "b-4")
+            .is_empty());
+        assert_eq!(
+            BackupRetention::new()
+                .with_keep_last(2)
+                .expired(backups.clone(), now, "b-4"),
+            vec!["b-2", "b-1"]
+        );
+        assert_eq!(
+            BackupRetention::new()
+                .with_max_age(day * 2)
+                .expired(backups.clone(), now, "b-4"),
+            vec!["b-1"]
+        );
+        assert_eq!(
+            BackupRetention::new()
+                .with_keep_last(0)
+                .expired(backups, now, "b-4"),
+            vec!["b-3", "b-2", "b-1"]
+        );
+    }
+
+    #[test]
+    fn test_backup_create_request_config() {
+        let req = BackupCreateRequest::builder("my-backup")
+            .with_include(vec!["Article"])
+            .with_config(
+                BackupConfig::new()
+                    .with_cpu_percentage(40)
+                    .with_chunk_size(256)
+                    .with_compression_level(CompressionLevel::BestSpeed)
+                    .with_bucket("nightly")
+                    .with_path("weaviate/2024"),
+            )
+            .build();
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "id": "my-backup",
+                "include": ["Article"],
+                "exclude": null,
+                "config": {
+                    "CPUPercentage": 40,
+                    "ChunkSize": 256,
+                    "CompressionLevel": "BestSpeed",
+                    "Bucket": "nightly",
+                    "Path": "weaviate/2024"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_backup_create_request_without_config() {
+        let req = BackupCreateRequest::builder("my-backup").build();
+        let value = serde_json::to_value(&req).unwrap();
+        assert!(value.get("config").is_none());
+    }
+
+    #[test]
+    fn test_backup_restore_request_config() {
+        let req = BackupRestoreRequest::builder()
+            .with_exclude(vec!["Article"])
+            .with_config(
+                RestoreConfig::new()
+                    .with_cpu_percentage(60)
+                    .with_bucket("nightly")
+                    .with_path("weaviate/2024")
+                    .with_roles_options(RbacRestoreOption::All)
+                    .with_users_options(RbacRestoreOption::NoRestore),
+            )
+            .with_node_mapping(vec![("node1", "weaviate-0")])
+            .with_overwrite_alias(true)
+            .build();
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "include": null,
+                "exclude": ["Article"],
+                "config": {
+                    "CPUPercentage": 60,
+                    "Bucket": "nightly",
+                    "Path": "weaviate/2024",
+                    "rolesOptions": "all",
+                    "usersOptions": "noRestore"
+                },
+                "node_mapping": {"node1": "weaviate-0"},
+                "overwriteAlias": true
+            })
+        );
+    }
+}
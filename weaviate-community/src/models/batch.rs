@@ -1,4 +1,4 @@
-use crate::models::objects::Object;
+use crate::models::objects::{Beacon, Object};
 /// All batch associated type components
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -365,7 +365,13 @@ pub struct BatchAddReferencesResponse(pub Vec<BatchAddReferenceResponse>);
 /// There should be no need to make this manually.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct BatchAddReferenceResponse {
-    result: BatchAddReferenceResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub from: Option<Beacon>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub to: Option<Beacon>,
+    pub result: BatchAddReferenceResult,
 }
 
 /// The response field of the BatchAddReferenceResponse
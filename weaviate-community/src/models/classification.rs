@@ -1,4 +1,6 @@
 /// All classification associated type components
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -187,6 +189,31 @@ impl ClassificationRequestBuilder {
         self
     }
 
+    /// Set the `classification_type` and `settings` of the ClassificationRequest from typed
+    /// settings.
+    ///
+    /// # Parameters
+    /// - settings: the typed settings for the classifier
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::classification::{
+    ///     ClassificationRequestBuilder,
+    ///     ClassificationSettings
+    /// };
+    ///
+    /// let builder = ClassificationRequestBuilder::new()
+    ///     .with_classification_settings(ClassificationSettings::knn(3));
+    /// ```
+    pub fn with_classification_settings(
+        mut self,
+        settings: ClassificationSettings,
+    ) -> ClassificationRequestBuilder {
+        self.classification_type = settings.classification_type();
+        self.settings = settings.to_value();
+        self
+    }
+
     /// Build the ClassificationRequest from the ClassificationRequestBuilder
     ///
     /// # Example
@@ -240,15 +267,113 @@ impl ClassificationRequestBuilder {
 }
 
 /// Types of classification available
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq)]
 pub enum ClassificationType {
     #[default]
     #[serde(rename = "knn")]
     KNN,
     #[serde(rename = "zeroshot")]
     ZEROSHOT,
+    #[serde(rename = "text2vec-contextionary-contextual")]
+    CONTEXTUAL,
+}
+
+/// Typed settings for each type of classification.
+///
+/// Zero-shot classification has no settings, and only sets the classification type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClassificationSettings {
+    Knn { k: u32 },
+    ZeroShot,
+}
+
+impl ClassificationSettings {
+    /// Create new kNN settings, voting between the `k` nearest neighbours.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::classification::ClassificationSettings;
+    ///
+    /// let settings = ClassificationSettings::knn(3);
+    /// ```
+    pub fn knn(k: u32) -> ClassificationSettings {
+        ClassificationSettings::Knn { k }
+    }
+
+    /// Create new zero-shot settings.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::classification::ClassificationSettings;
+    ///
+    /// let settings = ClassificationSettings::zero_shot();
+    /// ```
+    pub fn zero_shot() -> ClassificationSettings {
+        ClassificationSettings::ZeroShot
+    }
+
+    /// Get the type of classification the settings are for.
+    pub fn classification_type(&self) -> ClassificationType {
+        match self {
+            ClassificationSettings::Knn { .. } => ClassificationType::KNN,
+            ClassificationSettings::ZeroShot => ClassificationType::ZEROSHOT,
+        }
+    }
+
+    /// Get the settings as the JSON `settings` of a request.
+    fn to_value(&self) -> Option<serde_json::Value> {
+        match self {
+            ClassificationSettings::Knn { k } => Some(serde_json::json!({ "k": k })),
+            ClassificationSettings::ZeroShot => None,
+        }
+    }
+}
+
+/// The status of a classification.
+///
+/// Statuses that are not known by this crate are deserialized into
+/// `ClassificationStatus::Unknown`.
+///
+/// This shouldn't be something you create yourself, as it is returned by the appropriate
+/// endpoint when deserialized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClassificationStatus {
+    RUNNING,
+    COMPLETED,
+    FAILED,
+    Unknown(String),
+}
+
+impl ClassificationStatus {
+    /// Retrieve the string value associated to the ClassificationStatus enum types.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::classification::ClassificationStatus;
+    ///
+    /// let completed = ClassificationStatus::COMPLETED.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            ClassificationStatus::RUNNING => "running",
+            ClassificationStatus::COMPLETED => "completed",
+            ClassificationStatus::FAILED => "failed",
+            ClassificationStatus::Unknown(status) => status,
+        }
+    }
+
+    fn from_value(status: String) -> ClassificationStatus {
+        match status.as_str() {
+            "running" => ClassificationStatus::RUNNING,
+            "completed" => ClassificationStatus::COMPLETED,
+            "failed" => ClassificationStatus::FAILED,
+            _ => ClassificationStatus::Unknown(status),
+        }
+    }
 }
 
+impl_status_serde!(ClassificationStatus);
+
 /// Response received from the classification
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -258,23 +383,277 @@ pub struct ClassificationResponse {
     pub classify_properties: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub based_on_properties: Option<Vec<String>>,
-    pub status: String,
+    pub status: ClassificationStatus,
+    #[serde(deserialize_with = "empty_string_as_none")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub error: Option<String>,
     pub meta: ClassificationMetadata,
     #[serde(rename = "type")]
-    pub classification_type: String,
+    pub classification_type: ClassificationType,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub settings: Option<serde_json::Value>,
     pub filters: serde_json::Value,
 }
 
+impl ClassificationResponse {
+    /// Get the typed settings of the classification, if its type and settings are known.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::classification::{
+    ///     ClassificationResponse,
+    ///     ClassificationSettings
+    /// };
+    ///
+    /// let res: ClassificationResponse = serde_json::from_value(serde_json::json!({
+    ///     "id": "ee722219-b8ec-4db1-8f8d-5150bb1a9e0c",
+    ///     "class": "Article",
+    ///     "classifyProperties": ["hasPopularity"],
+    ///     "status": "running",
+    ///     "meta": {"started": "2023-11-01T12:00:00.000Z"},
+    ///     "type": "knn",
+    ///     "settings": {"k": 3},
+    ///     "filters": {}
+    /// })).unwrap();
+    /// assert_eq!(res.classification_settings(), Some(ClassificationSettings::knn(3)));
+    /// ```
+    pub fn classification_settings(&self) -> Option<ClassificationSettings> {
+        match self.classification_type {
+            ClassificationType::KNN => {
+                let k = self.settings.as_ref()?.get("k")?.as_u64()?;
+                Some(ClassificationSettings::knn(u32::try_from(k).ok()?))
+            }
+            ClassificationType::ZEROSHOT => Some(ClassificationSettings::zero_shot()),
+            ClassificationType::CONTEXTUAL => None,
+        }
+    }
+}
+
+/// Deserialize an optional string, where the empty string the server sends when unset is `None`.
+fn empty_string_as_none<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|s| !s.is_empty()))
+}
+
 /// Metadata for the Classification
+///
+/// The `started` and `completed` times are `None` while they are not set, such as `completed`
+/// while the classification is running.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ClassificationMetadata {
-    pub started: String,
-    pub completed: String,
+    #[serde(with = "rfc3339_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub started: Option<SystemTime>,
+    #[serde(with = "rfc3339_time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub completed: Option<SystemTime>,
     pub count: Option<u64>,
     pub count_succeeded: Option<u64>,
     pub count_failed: Option<u64>,
 }
+
+impl ClassificationMetadata {
+    /// Get the time the classification took, if it has completed.
+    pub fn duration(&self) -> Option<Duration> {
+        self.completed?.duration_since(self.started?).ok()
+    }
+}
+
+/// Serde for optional times as RFC 3339 timestamps, where unset times are `None`.
+mod rfc3339_time {
+    use super::*;
+
+    pub fn serialize<S: serde::Serializer>(
+        time: &Option<SystemTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let since_epoch = match time {
+            Some(time) => time
+                .duration_since(UNIX_EPOCH)
+                .map_err(|_| serde::ser::Error::custom("time is before the Unix epoch"))?,
+            None => Duration::ZERO,
+        };
+        serializer.serialize_str(&crate::format_rfc3339(since_epoch))
+    }
+
+    /// Timestamps that can not be parsed, such as the `0001-01-01T00:00:00Z` zero time that
+    /// Weaviate uses for unset times, are `None` rather than failing the whole response.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<SystemTime>, D::Error> {
+        let timestamp = Option::<String>::deserialize(deserializer)?;
+        Ok(timestamp
+            .as_deref()
+            .and_then(crate::parse_rfc3339)
+            .map(|since_epoch| UNIX_EPOCH + since_epoch))
+    }
+}
+
+/// Options for waiting for a classification to complete.
+///
+/// By default, the status is polled every second with no timeout.
+#[derive(Debug, Clone)]
+pub struct ClassificationWaitOptions {
+    pub poll_interval: Duration,
+    pub timeout: Option<Duration>,
+}
+
+impl ClassificationWaitOptions {
+    /// Create new ClassificationWaitOptions with the default values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::classification::ClassificationWaitOptions;
+    ///
+    /// let options = ClassificationWaitOptions::new();
+    /// ```
+    pub fn new() -> ClassificationWaitOptions {
+        ClassificationWaitOptions {
+            poll_interval: Duration::from_secs(1),
+            timeout: None,
+        }
+    }
+
+    /// Set the time to wait between requests for the status of the classification.
+    ///
+    /// # Parameters
+    /// - poll_interval: the time to wait between requests
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use weaviate_community::models::classification::ClassificationWaitOptions;
+    ///
+    /// let options = ClassificationWaitOptions::new().with_poll_interval(Duration::from_millis(500));
+    /// ```
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> ClassificationWaitOptions {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set the maximum time to wait for the classification to complete.
+    ///
+    /// If the classification has not completed in time, `WeaviateError::ClassificationTimeout`
+    /// is returned. The classification itself keeps running.
+    ///
+    /// # Parameters
+    /// - timeout: the maximum time to wait
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use weaviate_community::models::classification::ClassificationWaitOptions;
+    ///
+    /// let options = ClassificationWaitOptions::new().with_timeout(Duration::from_secs(600));
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> ClassificationWaitOptions {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+impl Default for ClassificationWaitOptions {
+    fn default() -> Self {
+        ClassificationWaitOptions::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_response(status: &str, completed: &str) -> ClassificationResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": "ee722219-b8ec-4db1-8f8d-5150bb1a9e0c",
+            "class": "Article",
+            "classifyProperties": ["hasPopularity"],
+            "basedOnProperties": ["summary"],
+            "status": status,
+            "error": "",
+            "meta": {
+                "started": "2023-11-01T12:00:00.250Z",
+                "completed": completed,
+                "count": 100,
+                "countSucceeded": 99,
+                "countFailed": 1
+            },
+            "type": "knn",
+            "settings": {"k": 3},
+            "filters": {}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_classification_response_typed() {
+        let res = test_response("completed", "2023-11-01T13:00:01.250+01:00");
+        assert_eq!(res.status, ClassificationStatus::COMPLETED);
+        assert_eq!(res.classification_type, ClassificationType::KNN);
+        assert_eq!(
+            res.meta.started,
+            Some(UNIX_EPOCH + Duration::from_millis(1_698_840_000_250))
+        );
+        assert_eq!(res.meta.duration(), Some(Duration::from_secs(1)));
+        assert_eq!(
+            res.classification_settings(),
+            Some(ClassificationSettings::knn(3))
+        );
+
+        let value = serde_json::to_value(&res).unwrap();
+        assert_eq!(value["status"], "completed");
+        assert_eq!(value["meta"]["started"], "2023-11-01T12:00:00.250Z");
+        assert_eq!(value["meta"]["completed"], "2023-11-01T12:00:01.250Z");
+    }
+
+    #[test]
+    fn test_classification_response_running() {
+        let res = test_response("running", "0001-01-01T00:00:00.000Z");
+        assert_eq!(res.status, ClassificationStatus::RUNNING);
+        assert!(res.meta.completed.is_none());
+        assert!(res.meta.duration().is_none());
+        assert!(res.error.is_none());
+
+        let res = test_response("running", "1969-12-31T23:59:59Z");
+        assert!(res.meta.completed.is_none());
+
+        let res = test_response("paused", "0001-01-01T00:00:00Z");
+        assert_eq!(res.status, ClassificationStatus::Unknown("paused".into()));
+        assert_eq!(res.status.value(), "paused");
+    }
+
+    #[test]
+    fn test_classification_metadata_serialize_before_epoch() {
+        let meta = ClassificationMetadata {
+            started: Some(UNIX_EPOCH - Duration::from_secs(1)),
+            completed: None,
+            count: None,
+            count_succeeded: None,
+            count_failed: None,
+        };
+        assert!(serde_json::to_value(&meta).is_err());
+    }
+
+    #[test]
+    fn test_classification_settings() {
+        let req = ClassificationRequest::builder()
+            .with_class("Article")
+            .with_classification_settings(ClassificationSettings::knn(5))
+            .build();
+        let value = serde_json::to_value(&req).unwrap();
+        assert_eq!(value["type"], "knn");
+        assert_eq!(value["settings"], serde_json::json!({"k": 5}));
+
+        let req = ClassificationRequest::builder()
+            .with_classification_settings(ClassificationSettings::zero_shot())
+            .build();
+        let value = serde_json::to_value(&req).unwrap();
+        assert_eq!(value["type"], "zeroshot");
+        assert!(value.get("settings").is_none());
+    }
+}
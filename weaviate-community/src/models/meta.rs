@@ -1,12 +1,400 @@
 /// All meta associated type components
-use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::{VersionParseError, WeaviateError};
 
 /// The Metadata struct used to contain all of the results returned from the get_meta endpoint.
 ///
 /// There should never be a need for this to be created manually.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Metadata {
     pub hostname: String,
-    pub modules: serde_json::Value,
-    pub version: String,
+    #[serde(default)]
+    pub modules: BTreeMap<String, ModuleInfo>,
+    pub version: ServerVersion,
+}
+
+impl Metadata {
+    /// Check if the server has the module with the given name enabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::meta::Metadata;
+    ///
+    /// let metadata: Metadata = serde_json::from_value(serde_json::json!({
+    ///     "hostname": "http://[::]:8080",
+    ///     "modules": {"text2vec-openai": {}},
+    ///     "version": "1.24.1"
+    /// })).unwrap();
+    /// assert!(metadata.has_module("text2vec-openai"));
+    /// ```
+    pub fn has_module(&self, name: &str) -> bool {
+        self.modules.contains_key(name)
+    }
+
+    /// Check if the server supports the capability.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::meta::{Capability, Metadata};
+    ///
+    /// let metadata: Metadata = serde_json::from_value(serde_json::json!({
+    ///     "hostname": "http://[::]:8080",
+    ///     "version": "1.24.1"
+    /// })).unwrap();
+    /// assert!(metadata.supports(&Capability::NamedVectors));
+    /// assert!(!metadata.supports(&Capability::TenantOffloading));
+    /// ```
+    pub fn supports(&self, capability: &Capability) -> bool {
+        if let Some(minimum) = capability.minimum_version() {
+            if self.version < minimum {
+                return false;
+            }
+        }
+        match capability {
+            Capability::TenantOffloading => self.modules.keys().any(|m| m.starts_with("offload-")),
+            Capability::Module(name) => self.has_module(name),
+            Capability::NamedVectors | Capability::Grpc => true,
+        }
+    }
+
+    /// Check if the server supports the capability, returning an error if it does not.
+    ///
+    /// # Errors
+    /// `WeaviateError::Unsupported` if the server does not support the capability.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::meta::{Capability, Metadata};
+    ///
+    /// let metadata: Metadata = serde_json::from_value(serde_json::json!({
+    ///     "hostname": "http://[::]:8080",
+    ///     "version": "1.23.0"
+    /// })).unwrap();
+    /// assert!(metadata.require(&Capability::NamedVectors).is_err());
+    /// ```
+    pub fn require(&self, capability: &Capability) -> Result<(), WeaviateError> {
+        if self.supports(capability) {
+            return Ok(());
+        }
+        Err(WeaviateError::Unsupported {
+            capability: capability.clone(),
+            version: self.version.clone(),
+        })
+    }
+}
+
+/// The information of a module enabled on the server.
+///
+/// Most modules report their `version`, any other information is kept in `other`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ModuleInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(flatten)]
+    pub other: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The semantic version of a Weaviate server, such as `1.24.1` or `1.25.0-rc.0`.
+///
+/// Versions are ordered by their major, minor and patch numbers, with pre-releases before the
+/// release itself. Pre-releases are ordered by their dot-separated identifiers as in semantic
+/// versioning, so `rc.2` is before `rc.10`. Build metadata, such as `+abc` in `1.24.1+abc`, is
+/// ignored.
+///
+/// # Example
+/// ```rust
+/// use weaviate_community::models::meta::ServerVersion;
+///
+/// let version: ServerVersion = "1.25.0-rc.0".parse().unwrap();
+/// assert!(version < ServerVersion::new(1, 25, 0));
+/// assert!(version.at_least(1, 24, 0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServerVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Option<String>,
+}
+
+impl ServerVersion {
+    /// Create a new release ServerVersion.
+    pub fn new(major: u64, minor: u64, patch: u64) -> ServerVersion {
+        ServerVersion {
+            major,
+            minor,
+            patch,
+            pre: None,
+        }
+    }
+
+    /// Check if the version is the given release or later.
+    pub fn at_least(&self, major: u64, minor: u64, patch: u64) -> bool {
+        *self >= ServerVersion::new(major, minor, patch)
+    }
+}
+
+impl std::str::FromStr for ServerVersion {
+    type Err = VersionParseError;
+
+    /// Parse a version, with an optional `v` prefix and an optional missing patch number.
+    ///
+    /// Build metadata after a `+` is not kept.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || VersionParseError(s.into());
+        let trimmed = s.trim().trim_start_matches('v');
+        let trimmed = match trimmed.split_once('+') {
+            Some((version, build)) if !build.is_empty() => version,
+            Some(_) => return Err(err()),
+            None => trimmed,
+        };
+        let (release, pre) = match trimmed.split_once('-') {
+            Some((release, pre)) if !pre.is_empty() => (release, Some(pre.to_string())),
+            Some(_) => return Err(err()),
+            None => (trimmed, None),
+        };
+        let mut numbers = release
+            .split('.')
+            .map(|n| n.parse::<u64>().map_err(|_| err()));
+        let major = numbers.next().ok_or_else(err)??;
+        let minor = numbers.next().ok_or_else(err)??;
+        let patch = numbers.next().transpose()?.unwrap_or(0);
+        if numbers.next().is_some() {
+            return Err(err());
+        }
+        Ok(ServerVersion {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+}
+
+impl std::fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{pre}")?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialOrd for ServerVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ServerVersion {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => std::cmp::Ordering::Equal,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (Some(a), Some(b)) => compare_pre_release(a, b),
+            })
+    }
+}
+
+/// Compare pre-release tags by their dot-separated identifiers, where numeric identifiers are
+/// compared as numbers and are before alphanumeric identifiers.
+///
+/// Identifiers with leading zeros are not numeric in semantic versioning, so they are compared as
+/// alphanumeric identifiers, which keeps the ordering consistent with equality.
+fn compare_pre_release(a: &str, b: &str) -> std::cmp::Ordering {
+    let numeric = |identifier: &str| match identifier.strip_prefix('0') {
+        Some(rest) if !rest.is_empty() => None,
+        _ => identifier.parse::<u64>().ok(),
+    };
+    let mut a = a.split('.');
+    let mut b = b.split('.');
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return std::cmp::Ordering::Equal,
+            (None, Some(_)) => return std::cmp::Ordering::Less,
+            (Some(_), None) => return std::cmp::Ordering::Greater,
+            (Some(a), Some(b)) => match (numeric(a), numeric(b)) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => a.cmp(b),
+            },
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+}
+
+impl Serialize for ServerVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ServerVersion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A feature that is only available on some Weaviate servers, see `Metadata::supports`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Capability {
+    /// Multiple named vectors per object, from Weaviate 1.24.0.
+    NamedVectors,
+    /// The gRPC API, from Weaviate 1.23.7.
+    Grpc,
+    /// Offloading inactive tenants to cloud storage, from Weaviate 1.26.0 with an `offload-*`
+    /// module enabled.
+    TenantOffloading,
+    /// The module with the given name being enabled.
+    Module(String),
+}
+
+impl Capability {
+    /// The first version of Weaviate supporting the capability, if it depends on the version.
+    pub fn minimum_version(&self) -> Option<ServerVersion> {
+        match self {
+            Capability::NamedVectors => Some(ServerVersion::new(1, 24, 0)),
+            Capability::Grpc => Some(ServerVersion::new(1, 23, 7)),
+            Capability::TenantOffloading => Some(ServerVersion::new(1, 26, 0)),
+            Capability::Module(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Capability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Capability::NamedVectors => write!(f, "named vectors"),
+            Capability::Grpc => write!(f, "gRPC"),
+            Capability::TenantOffloading => write!(f, "tenant offloading"),
+            Capability::Module(name) => write!(f, "module `{name}`"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_server_version_parse() {
+        let version: ServerVersion = "1.24.1".parse().unwrap();
+        assert_eq!(version, ServerVersion::new(1, 24, 1));
+        assert_eq!(version.to_string(), "1.24.1");
+        assert_eq!(
+            "v1.25".parse::<ServerVersion>().unwrap(),
+            ServerVersion::new(1, 25, 0)
+        );
+        let rc: ServerVersion = "1.25.0-rc.0".parse().unwrap();
+        assert_eq!(rc.pre.as_deref(), Some("rc.0"));
+        assert_eq!(rc.to_string(), "1.25.0-rc.0");
+        assert!("1".parse::<ServerVersion>().is_err());
+        assert!("1.2.3.4".parse::<ServerVersion>().is_err());
+        assert!("1.x.0".parse::<ServerVersion>().is_err());
+        assert!("1.2.0-".parse::<ServerVersion>().is_err());
+        assert!("1.2.0+".parse::<ServerVersion>().is_err());
+    }
+
+    #[test]
+    fn test_server_version_build_metadata() {
+        let version: ServerVersion = "1.24.1+abc".parse().unwrap();
+        assert_eq!(version, ServerVersion::new(1, 24, 1));
+        assert_eq!(version.to_string(), "1.24.1");
+        let rc: ServerVersion = "1.25.0-rc.1+build.5".parse().unwrap();
+        assert_eq!(rc.pre.as_deref(), Some("rc.1"));
+    }
+
+    #[test]
+    fn test_server_version_ordering() {
+        let rc: ServerVersion = "1.25.0-rc.0".parse().unwrap();
+        assert!(rc < ServerVersion::new(1, 25, 0));
+        assert!(rc > ServerVersion::new(1, 24, 10));
+        assert!(ServerVersion::new(1, 9, 0) < ServerVersion::new(1, 10, 0));
+        assert!(ServerVersion::new(1, 23, 7).at_least(1, 23, 7));
+    }
+
+    #[test]
+    fn test_server_version_pre_release_ordering() {
+        let versions: Vec<ServerVersion> = [
+            "1.25.0-alpha",
+            "1.25.0-alpha.1",
+            "1.25.0-alpha.beta",
+            "1.25.0-beta.2",
+            "1.25.0-beta.11",
+            "1.25.0-rc.2",
+            "1.25.0-rc.10",
+            "1.25.0",
+        ]
+        .iter()
+        .map(|version| version.parse().unwrap())
+        .collect();
+        for pair in versions.windows(2) {
+            assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+        }
+        let leading_zero: ServerVersion = "1.25.0-rc.01".parse().unwrap();
+        assert!(leading_zero > "1.25.0-rc.1".parse().unwrap());
+    }
+
+    #[test]
+    fn test_metadata_capabilities() {
+        let metadata: Metadata = serde_json::from_value(serde_json::json!({
+            "hostname": "http://[::]:8080",
+            "modules": {
+                "offload-s3": {"name": "offload-s3"},
+                "text2vec-contextionary": {
+                    "version": "en0.16.0-v0.4.21",
+                    "wordCount": 818072
+                }
+            },
+            "version": "1.26.2"
+        }))
+        .unwrap();
+        let contextionary = &metadata.modules["text2vec-contextionary"];
+        assert_eq!(contextionary.version.as_deref(), Some("en0.16.0-v0.4.21"));
+        assert_eq!(contextionary.other["wordCount"], 818072);
+        assert!(metadata.supports(&Capability::NamedVectors));
+        assert!(metadata.supports(&Capability::Grpc));
+        assert!(metadata.supports(&Capability::TenantOffloading));
+        assert!(metadata
+            .require(&Capability::Module("text2vec-contextionary".into()))
+            .is_ok());
+        assert!(matches!(
+            metadata.require(&Capability::Module("text2vec-openai".into())),
+            Err(WeaviateError::Unsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn test_metadata_old_server() {
+        let metadata: Metadata = serde_json::from_value(serde_json::json!({
+            "hostname": "http://[::]:8080",
+            "modules": {},
+            "version": "1.23.0"
+        }))
+        .unwrap();
+        assert!(!metadata.supports(&Capability::Grpc));
+        let err = metadata.require(&Capability::NamedVectors).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Weaviate 1.23.0 does not support named vectors, which requires Weaviate 1.24.0 or later"
+        );
+    }
 }
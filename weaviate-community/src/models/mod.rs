@@ -1,3 +1,23 @@
+/// Implement `Serialize` and `Deserialize` through the `value` and `from_value` functions of
+/// status enums, so statuses that are not known by this crate are kept as `Unknown`.
+macro_rules! impl_status_serde {
+    ($($ty:ty),*) => {
+        $(
+            impl serde::Serialize for $ty {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(self.value())
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $ty {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    Ok(<$ty>::from_value(<String as serde::Deserialize>::deserialize(deserializer)?))
+                }
+            }
+        )*
+    };
+}
+
 pub mod auth;
 pub mod backups;
 pub mod batch;
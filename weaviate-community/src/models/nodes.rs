@@ -57,10 +57,12 @@ pub struct BatchStats {
 /// This shouldn't be something you create yourself, as it is returned by the appropriate
 /// endpoint when deserialized.
 #[derive(Serialize, Deserialize, Debug)]
-pub struct NodeShards(Vec<NodeShard>);
+pub struct NodeShards(pub Vec<NodeShard>);
 
 /// The NodeShard definitions of a Shard in the node.
 ///
+/// Shards are only returned with `NodeOutput::Verbose`.
+///
 /// This shouldn't be something you create yourself, as it is returned by the appropriate
 /// endpoint when deserialized.
 #[derive(Serialize, Deserialize, Debug)]
@@ -68,19 +70,25 @@ pub struct NodeShards(Vec<NodeShard>);
 pub struct NodeShard {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    class: Option<String>,
+    pub class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub object_count: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    name: Option<String>,
+    pub vector_indexing_status: Option<VectorIndexingStatus>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    object_count: Option<u64>,
+    pub vector_queue_length: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    vector_indexing_status: Option<String>,
+    pub compressed: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    vector_queue_length: Option<u64>,
+    pub loaded: Option<bool>,
 }
 
 /// The NodeStats of the node.
@@ -92,20 +100,160 @@ pub struct NodeShard {
 pub struct NodeStats {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    object_count: Option<u64>,
+    pub object_count: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
-    shard_count: Option<u64>,
+    pub shard_count: Option<u64>,
 }
 
 /// The NodeStatus of the node.
 ///
+/// Statuses that are not known by this crate are deserialized into `NodeStatus::Unknown`.
+///
 /// This shouldn't be something you create yourself, as it is returned by the appropriate
 /// endpoint when deserialized.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NodeStatus {
     HEALTHY,
     UNHEALTHY,
     UNAVAILABLE,
     INDEXING,
+    Unknown(String),
+}
+
+impl NodeStatus {
+    /// Retrieve the string value associated to the NodeStatus enum types.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::nodes::NodeStatus;
+    ///
+    /// let healthy = NodeStatus::HEALTHY.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            NodeStatus::HEALTHY => "HEALTHY",
+            NodeStatus::UNHEALTHY => "UNHEALTHY",
+            NodeStatus::UNAVAILABLE => "UNAVAILABLE",
+            NodeStatus::INDEXING => "INDEXING",
+            NodeStatus::Unknown(status) => status,
+        }
+    }
+
+    fn from_value(status: String) -> NodeStatus {
+        match status.as_str() {
+            "HEALTHY" => NodeStatus::HEALTHY,
+            "UNHEALTHY" => NodeStatus::UNHEALTHY,
+            "UNAVAILABLE" => NodeStatus::UNAVAILABLE,
+            "INDEXING" => NodeStatus::INDEXING,
+            _ => NodeStatus::Unknown(status),
+        }
+    }
+}
+
+/// The indexing status of the vector index of a shard.
+///
+/// Statuses that are not known by this crate are deserialized into
+/// `VectorIndexingStatus::Unknown`.
+///
+/// This shouldn't be something you create yourself, as it is returned by the appropriate
+/// endpoint when deserialized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VectorIndexingStatus {
+    READY,
+    INDEXING,
+    READONLY,
+    Unknown(String),
+}
+
+impl VectorIndexingStatus {
+    /// Retrieve the string value associated to the VectorIndexingStatus enum types.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::nodes::VectorIndexingStatus;
+    ///
+    /// let ready = VectorIndexingStatus::READY.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            VectorIndexingStatus::READY => "READY",
+            VectorIndexingStatus::INDEXING => "INDEXING",
+            VectorIndexingStatus::READONLY => "READONLY",
+            VectorIndexingStatus::Unknown(status) => status,
+        }
+    }
+
+    fn from_value(status: String) -> VectorIndexingStatus {
+        match status.as_str() {
+            "READY" => VectorIndexingStatus::READY,
+            "INDEXING" => VectorIndexingStatus::INDEXING,
+            "READONLY" => VectorIndexingStatus::READONLY,
+            _ => VectorIndexingStatus::Unknown(status),
+        }
+    }
+}
+
+impl_status_serde!(NodeStatus, VectorIndexingStatus);
+
+/// The amount of detail in the node status.
+///
+/// `Minimal` is the default of the server, and does not include the shards of the nodes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeOutput {
+    Minimal,
+    Verbose,
+}
+
+impl NodeOutput {
+    /// Retrieve the string value associated to the NodeOutput enum types.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::nodes::NodeOutput;
+    ///
+    /// let verbose = NodeOutput::Verbose.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            NodeOutput::Minimal => "minimal",
+            NodeOutput::Verbose => "verbose",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_status_unknown() {
+        let status: NodeStatus = serde_json::from_value(serde_json::json!("TIMEOUT")).unwrap();
+        assert_eq!(status, NodeStatus::Unknown("TIMEOUT".into()));
+        assert_eq!(serde_json::to_value(&status).unwrap(), "TIMEOUT");
+        let status: NodeStatus = serde_json::from_value(serde_json::json!("HEALTHY")).unwrap();
+        assert_eq!(status, NodeStatus::HEALTHY);
+    }
+
+    #[test]
+    fn test_node_shard_verbose() {
+        let shard: NodeShard = serde_json::from_value(serde_json::json!({
+            "class": "Article",
+            "name": "nq1Bg9Q5lxxP",
+            "objectCount": 12,
+            "vectorIndexingStatus": "READONLY",
+            "vectorQueueLength": 3,
+            "compressed": true,
+            "loaded": false
+        }))
+        .unwrap();
+        assert_eq!(shard.class.as_deref(), Some("Article"));
+        assert_eq!(
+            shard.vector_indexing_status,
+            Some(VectorIndexingStatus::READONLY)
+        );
+        assert_eq!(shard.vector_queue_length, Some(3));
+        assert_eq!(shard.compressed, Some(true));
+        assert_eq!(shard.loaded, Some(false));
+    }
 }
@@ -2,6 +2,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::error::BeaconParseError;
+
 /// Wrapper for multiple objects.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MultiObjects {
@@ -73,6 +75,130 @@ impl Object {
     pub fn builder(class: &str, properties: serde_json::Value) -> ObjectBuilder {
         ObjectBuilder::new(class, properties)
     }
+
+    /// Get the references held by a reference property of the object.
+    ///
+    /// Returns an empty list if the object does not have the property.
+    ///
+    /// # Parameters
+    /// - property_name: the name of the reference property
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::Object;
+    ///
+    /// let object = Object::builder("JeopardyQuestion", serde_json::json!({
+    ///     "hasCategory": [
+    ///         {"beacon": "weaviate://localhost/JeopardyCategory/20ffc68d-986b-5e71-a680-228dba18d7ef"}
+    ///     ]
+    /// })).build();
+    ///
+    /// let references = object.references("hasCategory").unwrap();
+    /// assert_eq!(references[0].beacon.class_name.as_deref(), Some("JeopardyCategory"));
+    /// ```
+    pub fn references(&self, property_name: &str) -> Result<Vec<SingleRef>, serde_json::Error> {
+        match self.properties.get(property_name) {
+            Some(references) => Vec::<SingleRef>::deserialize(references),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// Generate a deterministic v5 UUID from an identifier, prefixed with a namespace.
+///
+/// The UUID is generated the same way as `generate_uuid5` in the official Weaviate clients, in the
+/// DNS namespace from the `namespace` followed by the identifier. Strings and numbers give the
+/// same UUID as the official clients. Other values are serialized as compact JSON, with the keys
+/// of maps in sorted order.
+///
+/// This is useful for idempotent imports, where importing the same data twice should update the
+/// existing object rather than create a new one.
+///
+/// # Parameters
+/// - identifier: the data to generate the UUID from
+/// - namespace: the prefix of the identifier, such as the class name
+///
+/// # Example
+/// ```rust
+/// use weaviate_community::models::objects::generate_uuid5;
+///
+/// let uuid = generate_uuid5("hello", "").unwrap();
+/// assert_eq!(uuid.to_string(), "9342d47a-1bab-5709-9869-c840b2eac501");
+///
+/// let uuid = generate_uuid5(&serde_json::json!({"title": "A", "year": 2020}), "Article").unwrap();
+/// ```
+pub fn generate_uuid5<T: Serialize + ?Sized>(
+    identifier: &T,
+    namespace: &str,
+) -> Result<Uuid, serde_json::Error> {
+    Ok(uuid5_from_value(
+        &serde_json::to_value(identifier)?,
+        namespace,
+    ))
+}
+
+/// Generate a deterministic v5 UUID from some of the properties of an object.
+///
+/// The selected properties are collected into a map, which is used as the identifier of
+/// `generate_uuid5`. Properties that the object does not have are skipped.
+///
+/// # Parameters
+/// - properties: the properties of the object
+/// - property_names: the names of the properties to generate the UUID from
+/// - namespace: the prefix of the identifier, such as the class name
+///
+/// # Example
+/// ```rust
+/// use weaviate_community::models::objects::generate_uuid5_from_properties;
+///
+/// let properties = serde_json::json!({"title": "A", "year": 2020, "views": 10});
+/// let uuid = generate_uuid5_from_properties(&properties, &["title", "year"], "Article");
+/// ```
+pub fn generate_uuid5_from_properties(
+    properties: &serde_json::Value,
+    property_names: &[&str],
+    namespace: &str,
+) -> Uuid {
+    let selected: serde_json::Map<String, serde_json::Value> = property_names
+        .iter()
+        .filter_map(|name| Some((name.to_string(), properties.get(name)?.clone())))
+        .collect();
+    uuid5_from_value(&serde_json::Value::Object(selected), namespace)
+}
+
+fn uuid5_from_value(identifier: &serde_json::Value, namespace: &str) -> Uuid {
+    let identifier = match identifier {
+        serde_json::Value::String(identifier) => identifier.clone(),
+        identifier => sort_keys(identifier).to_string(),
+    };
+    Uuid::new_v5(
+        &Uuid::NAMESPACE_DNS,
+        format!("{namespace}{identifier}").as_bytes(),
+    )
+}
+
+/// Copy a value with the keys of all maps in sorted order.
+///
+/// `serde_json::Map` only keeps its keys sorted when the `preserve_order` feature of serde_json is
+/// not enabled by any crate in the build, so the keys are sorted explicitly to keep the generated
+/// UUIDs stable.
+fn sort_keys(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.clone(), sort_keys(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.iter().map(sort_keys).collect())
+        }
+        value => value.clone(),
+    }
 }
 
 /// The builder for an Object
@@ -134,6 +260,51 @@ impl ObjectBuilder {
         self
     }
 
+    /// Set the `id` of the object to a v5 UUID generated from all of its properties.
+    ///
+    /// The id is generated with `generate_uuid5`, using the class name as the namespace, so the
+    /// same properties always give the same id.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::ObjectBuilder;
+    ///
+    /// let object = ObjectBuilder::new("Article", serde_json::json!({"title": "A"}))
+    ///     .with_generated_id()
+    ///     .build();
+    /// assert!(object.id.is_some());
+    /// ```
+    pub fn with_generated_id(mut self) -> ObjectBuilder {
+        self.id = Some(uuid5_from_value(&self.properties, &self.class));
+        self
+    }
+
+    /// Set the `id` of the object to a v5 UUID generated from some of its properties.
+    ///
+    /// The id is generated with `generate_uuid5_from_properties`, using the class name as the
+    /// namespace, so objects with the same values for the selected properties get the same id.
+    ///
+    /// # Parameters
+    /// - property_names: the names of the properties to generate the id from
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::ObjectBuilder;
+    ///
+    /// let object = ObjectBuilder::new("Article", serde_json::json!({"title": "A", "views": 10}))
+    ///     .with_generated_id_from(&["title"])
+    ///     .build();
+    /// assert!(object.id.is_some());
+    /// ```
+    pub fn with_generated_id_from(mut self, property_names: &[&str]) -> ObjectBuilder {
+        self.id = Some(generate_uuid5_from_properties(
+            &self.properties,
+            property_names,
+            &self.class,
+        ));
+        self
+    }
+
     /// Add a value to the optional `vector` value of the object.
     ///
     /// # Parameters
@@ -219,6 +390,7 @@ impl ObjectBuilder {
 /// Strict definitions for ordering queries.
 ///
 /// The options available are ASC and DESC.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OrderBy {
     ASC,
     DESC,
@@ -241,6 +413,125 @@ impl OrderBy {
     }
 }
 
+/// A single sort criterion, used to sort objects by a property or a special field.
+///
+/// The same sort specification is used when listing objects through the REST API and in the
+/// `sort` of a GraphQL Get query. When no order is specified, the objects are sorted in ascending
+/// order.
+///
+/// More information on sorting can be found [here](https://weaviate.io/developers/weaviate/api/graphql/additional-operators#sorting)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sort {
+    pub path: Vec<String>,
+    pub order: Option<OrderBy>,
+}
+
+impl Sort {
+    /// Sort by the value of a property.
+    ///
+    /// # Parameters
+    /// - property: the name of the property to sort by
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::Sort;
+    ///
+    /// let sort = Sort::property("title");
+    /// ```
+    pub fn property(property: impl Into<String>) -> Sort {
+        Sort {
+            path: vec![property.into()],
+            order: None,
+        }
+    }
+
+    /// Sort by the time the objects were created (`_creationTimeUnix`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::Sort;
+    ///
+    /// let sort = Sort::creation_time().descending();
+    /// ```
+    pub fn creation_time() -> Sort {
+        Sort::property("_creationTimeUnix")
+    }
+
+    /// Sort by the time the objects were last updated (`_lastUpdateTimeUnix`).
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::Sort;
+    ///
+    /// let sort = Sort::last_update_time();
+    /// ```
+    pub fn last_update_time() -> Sort {
+        Sort::property("_lastUpdateTimeUnix")
+    }
+
+    /// Sort by the object id.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::Sort;
+    ///
+    /// let sort = Sort::id();
+    /// ```
+    pub fn id() -> Sort {
+        Sort::property("id")
+    }
+
+    /// Set the order to sort in.
+    ///
+    /// # Parameters
+    /// - order: the order to sort in
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::{OrderBy, Sort};
+    ///
+    /// let sort = Sort::property("title").with_order(OrderBy::DESC);
+    /// ```
+    pub fn with_order(mut self, order: OrderBy) -> Sort {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sort in ascending order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::Sort;
+    ///
+    /// let sort = Sort::property("title").ascending();
+    /// ```
+    pub fn ascending(self) -> Sort {
+        self.with_order(OrderBy::ASC)
+    }
+
+    /// Sort in descending order.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::objects::Sort;
+    ///
+    /// let sort = Sort::property("title").descending();
+    /// ```
+    pub fn descending(self) -> Sort {
+        self.with_order(OrderBy::DESC)
+    }
+}
+
+impl std::fmt::Display for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{path: {}", serde_json::Value::from(self.path.clone()))?;
+        if let Some(order) = &self.order {
+            write!(f, ", order: {}", order.value())?;
+        }
+        write!(f, "}}")
+    }
+}
+
 /// Strict definitions of consistency levels.
 ///
 /// For more information on consistency levels in Weaviate, check out the replication architecture
@@ -250,7 +541,7 @@ impl OrderBy {
 /// - QUORUM / QUORUM => balanced write and read latency
 /// - ONE / ALL => fast write and slow read (optimized for write)
 /// - ALL / ONE => slow write and fast read (optimized for read)
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub enum ConsistencyLevel {
     ONE,
     QUORUM,
@@ -282,8 +573,7 @@ pub struct ObjectListParameters {
     pub offset: Option<u64>,
     pub after: Option<String>,
     pub include: Option<String>,
-    pub sort: Option<Vec<String>>,
-    pub order: Option<Vec<String>>,
+    pub sort: Option<Vec<Sort>>,
 }
 
 impl ObjectListParameters {
@@ -321,8 +611,7 @@ pub struct ObjectListParametersBuilder {
     pub offset: Option<u64>,
     pub after: Option<String>,
     pub include: Option<String>,
-    pub sort: Option<Vec<String>>,
-    pub order: Option<Vec<String>>,
+    pub sort: Option<Vec<Sort>>,
 }
 
 impl ObjectListParametersBuilder {
@@ -442,35 +731,20 @@ impl ObjectListParametersBuilder {
 
     /// Add a value to the optional `sort` value to the parameters.
     ///
-    /// # Parameters
-    /// - sort: the sort value to set
-    ///
-    /// # Example
-    /// ```rust
-    /// use weaviate_community::models::objects::ObjectListParametersBuilder;
-    ///
-    /// let builder = ObjectListParametersBuilder::new().with_sort(vec!["title"]);
-    /// ```
-    pub fn with_sort(mut self, sort: Vec<&str>) -> ObjectListParametersBuilder {
-        let sort = sort.iter().map(|field| field.to_string()).collect();
-        self.sort = Some(sort);
-        self
-    }
-
-    /// Add a value to the optional `order` value to the parameters.
+    /// Cannot be used with `after`.
     ///
     /// # Parameters
-    /// - order: the order to set
+    /// - sort: the sort criteria to set, in order of priority
     ///
     /// # Example
     /// ```rust
-    /// use weaviate_community::models::objects::ObjectListParametersBuilder;
+    /// use weaviate_community::models::objects::{ObjectListParametersBuilder, Sort};
     ///
-    /// let builder = ObjectListParametersBuilder::new().with_order(vec!["asc"]);
+    /// let builder = ObjectListParametersBuilder::new()
+    ///     .with_sort(vec![Sort::property("title"), Sort::creation_time().descending()]);
     /// ```
-    pub fn with_order(mut self, order: Vec<&str>) -> ObjectListParametersBuilder {
-        let order = order.iter().map(|field| field.to_string()).collect();
-        self.order = Some(order);
+    pub fn with_sort(mut self, sort: Vec<Sort>) -> ObjectListParametersBuilder {
+        self.sort = Some(sort);
         self
     }
 
@@ -498,11 +772,161 @@ impl ObjectListParametersBuilder {
             after: self.after,
             include: self.include,
             sort: self.sort,
-            order: self.order,
         }
     }
 }
 
+/// The prefix of every beacon.
+const BEACON_PREFIX: &str = "weaviate://localhost/";
+
+/// A beacon pointing to an object, such as `weaviate://localhost/JeopardyCategory/{uuid}`.
+///
+/// Weaviate uses beacons to refer to objects in cross-references. Legacy beacons do not contain
+/// the class name of the object, and are of the form `weaviate://localhost/{uuid}`. The source of
+/// a batch reference also contains the property holding the reference, as in
+/// `weaviate://localhost/JeopardyQuestion/{uuid}/hasCategory`.
+///
+/// Beacons are serialized as their string form.
+///
+/// # Example
+/// ```rust
+/// use uuid::Uuid;
+/// use weaviate_community::models::objects::Beacon;
+///
+/// let uuid = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
+/// let beacon = Beacon::new("JeopardyCategory", uuid);
+/// assert_eq!(
+///     beacon.to_string(),
+///     "weaviate://localhost/JeopardyCategory/20ffc68d-986b-5e71-a680-228dba18d7ef"
+/// );
+///
+/// let legacy: Beacon = "weaviate://localhost/20ffc68d-986b-5e71-a680-228dba18d7ef"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(legacy.id, uuid);
+/// assert!(legacy.class_name.is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Beacon {
+    pub class_name: Option<String>,
+    pub id: Uuid,
+    pub property_name: Option<String>,
+}
+
+impl Beacon {
+    /// Create a new Beacon pointing to an object of the given class.
+    ///
+    /// # Example
+    /// ```rust
+    /// use uuid::Uuid;
+    /// use weaviate_community::models::objects::Beacon;
+    ///
+    /// let beacon = Beacon::new("JeopardyCategory", Uuid::new_v4());
+    /// ```
+    pub fn new(class_name: &str, id: Uuid) -> Beacon {
+        Beacon {
+            class_name: Some(class_name.into()),
+            id,
+            property_name: None,
+        }
+    }
+
+    /// Create a new legacy Beacon, which does not contain the class name of the object.
+    ///
+    /// # Example
+    /// ```rust
+    /// use uuid::Uuid;
+    /// use weaviate_community::models::objects::Beacon;
+    ///
+    /// let beacon = Beacon::legacy(Uuid::new_v4());
+    /// ```
+    pub fn legacy(id: Uuid) -> Beacon {
+        Beacon {
+            class_name: None,
+            id,
+            property_name: None,
+        }
+    }
+
+    /// Add the property holding the reference, as used for the source of a batch reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use uuid::Uuid;
+    /// use weaviate_community::models::objects::Beacon;
+    ///
+    /// let beacon = Beacon::new("JeopardyQuestion", Uuid::new_v4()).with_property_name("hasCategory");
+    /// ```
+    pub fn with_property_name(mut self, property_name: &str) -> Beacon {
+        self.property_name = Some(property_name.into());
+        self
+    }
+}
+
+impl std::fmt::Display for Beacon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{BEACON_PREFIX}")?;
+        if let Some(class_name) = &self.class_name {
+            write!(f, "{class_name}/")?;
+        }
+        write!(f, "{}", self.id)?;
+        if let Some(property_name) = &self.property_name {
+            write!(f, "/{property_name}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Beacon {
+    type Err = BeaconParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || BeaconParseError(s.into());
+        let path = s.strip_prefix(BEACON_PREFIX).ok_or_else(invalid)?;
+        let segments: Vec<&str> = path.split('/').collect();
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(invalid());
+        }
+        // Class names can not be UUIDs, so a leading UUID marks a legacy beacon
+        let (class_name, rest) = match Uuid::parse_str(segments[0]) {
+            Ok(_) => (None, &segments[..]),
+            Err(_) => (Some(segments[0].to_string()), &segments[1..]),
+        };
+        let (id, property_name) = match rest {
+            [id] => (id, None),
+            [id, property_name] => (id, Some(property_name.to_string())),
+            _ => return Err(invalid()),
+        };
+        Ok(Beacon {
+            class_name,
+            id: Uuid::parse_str(id).map_err(|_| invalid())?,
+            property_name,
+        })
+    }
+}
+
+impl Serialize for Beacon {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Beacon {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let beacon = String::deserialize(deserializer)?;
+        beacon.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A single reference in a reference property, as returned by Weaviate.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SingleRef {
+    pub beacon: Beacon,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub href: Option<String>,
+}
+
 /// Wrapper for multiple references.
 pub struct References(pub Vec<Reference>);
 
@@ -582,6 +1006,59 @@ impl Reference {
         }
     }
 
+    /// Get the beacon of the source of the reference, including the property holding it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use uuid::Uuid;
+    /// use weaviate_community::models::objects::Reference;
+    ///
+    /// let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    /// let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
+    ///
+    /// let reference = Reference::new(
+    ///     "JeopardyQuestion",
+    ///     uuid1,
+    ///     "hasCategory",
+    ///     "JeopardyCategory",
+    ///     uuid2,
+    /// );
+    /// assert_eq!(
+    ///     reference.from_beacon().to_string(),
+    ///     "weaviate://localhost/JeopardyQuestion/12345678-1234-1234-1234-123456789012/hasCategory"
+    /// );
+    /// ```
+    pub fn from_beacon(&self) -> Beacon {
+        Beacon::new(&self.from_class_name, self.from_uuid)
+            .with_property_name(&self.from_property_name)
+    }
+
+    /// Get the beacon of the target of the reference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use uuid::Uuid;
+    /// use weaviate_community::models::objects::Reference;
+    ///
+    /// let uuid1 = Uuid::parse_str("12345678-1234-1234-1234-123456789012").unwrap();
+    /// let uuid2 = Uuid::parse_str("20ffc68d-986b-5e71-a680-228dba18d7ef").unwrap();
+    ///
+    /// let reference = Reference::new(
+    ///     "JeopardyQuestion",
+    ///     uuid1,
+    ///     "hasCategory",
+    ///     "JeopardyCategory",
+    ///     uuid2,
+    /// );
+    /// assert_eq!(
+    ///     reference.to_beacon().to_string(),
+    ///     "weaviate://localhost/JeopardyCategory/20ffc68d-986b-5e71-a680-228dba18d7ef"
+    /// );
+    /// ```
+    pub fn to_beacon(&self) -> Beacon {
+        Beacon::new(&self.to_class_name, self.to_uuid)
+    }
+
     /// Create a new builder for the Reference.
     ///
     /// This is the same as `ReferenceBuilder::new()`.
@@ -770,3 +1247,153 @@ impl ReferenceBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "20ffc68d-986b-5e71-a680-228dba18d7ef";
+
+    #[test]
+    fn test_beacon_parse() {
+        let id = Uuid::parse_str(ID).unwrap();
+        let beacon: Beacon = format!("weaviate://localhost/JeopardyCategory/{ID}")
+            .parse()
+            .unwrap();
+        assert_eq!(beacon, Beacon::new("JeopardyCategory", id));
+
+        let legacy: Beacon = format!("weaviate://localhost/{ID}").parse().unwrap();
+        assert_eq!(legacy, Beacon::legacy(id));
+
+        let from: Beacon = format!("weaviate://localhost/JeopardyQuestion/{ID}/hasCategory")
+            .parse()
+            .unwrap();
+        assert_eq!(
+            from,
+            Beacon::new("JeopardyQuestion", id).with_property_name("hasCategory")
+        );
+    }
+
+    #[test]
+    fn test_beacon_parse_invalid() {
+        for beacon in [
+            format!("http://localhost/JeopardyCategory/{ID}"),
+            "weaviate://localhost/JeopardyCategory/not-a-uuid".to_string(),
+            format!("weaviate://localhost/JeopardyCategory/{ID}/"),
+            format!("weaviate://localhost/A/B/{ID}"),
+            "weaviate://localhost/".to_string(),
+        ] {
+            assert!(beacon.parse::<Beacon>().is_err(), "{beacon}");
+        }
+    }
+
+    #[test]
+    fn test_beacon_serde() {
+        let beacon = Beacon::new("JeopardyCategory", Uuid::parse_str(ID).unwrap());
+        let value = serde_json::to_value(&beacon).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!(format!("weaviate://localhost/JeopardyCategory/{ID}"))
+        );
+        assert_eq!(serde_json::from_value::<Beacon>(value).unwrap(), beacon);
+        assert!(serde_json::from_value::<Beacon>(serde_json::json!("nope")).is_err());
+    }
+
+    #[test]
+    fn test_generate_uuid5() {
+        // Values generated with `generate_uuid5` of the official Python client
+        assert_eq!(
+            generate_uuid5("hello", "").unwrap().to_string(),
+            "9342d47a-1bab-5709-9869-c840b2eac501"
+        );
+        assert_eq!(
+            generate_uuid5("hello", "Article").unwrap().to_string(),
+            "de0b17b9-9ce1-5e63-9a7e-cf3581cb6156"
+        );
+        assert_eq!(
+            generate_uuid5(&42, "").unwrap().to_string(),
+            "7c411b5e-9d3f-50b5-9c28-62096e41c4ed"
+        );
+    }
+
+    #[test]
+    fn test_generate_uuid5_from_properties() {
+        let properties = serde_json::json!({"year": 2020, "views": 10, "title": "A"});
+        let expected =
+            generate_uuid5(&serde_json::json!({"title": "A", "year": 2020}), "").unwrap();
+        assert_eq!(
+            generate_uuid5_from_properties(&properties, &["year", "title", "missing"], ""),
+            expected
+        );
+        assert_eq!(expected.to_string(), "92ae58bc-f669-5000-8825-481a4b9e4988");
+    }
+
+    #[test]
+    fn test_generate_uuid5_insertion_order() {
+        let map = |entries: Vec<(&str, serde_json::Value)>| {
+            let mut map = serde_json::Map::new();
+            for (key, value) in entries {
+                map.insert(key.into(), value);
+            }
+            serde_json::Value::Object(map)
+        };
+        let a = map(vec![
+            ("title", "A".into()),
+            (
+                "author",
+                map(vec![("name", "B".into()), ("age", 30.into())]),
+            ),
+        ]);
+        let b = map(vec![
+            (
+                "author",
+                map(vec![("age", 30.into()), ("name", "B".into())]),
+            ),
+            ("title", "A".into()),
+        ]);
+        let canonical = r#"{"author":{"age":30,"name":"B"},"title":"A"}"#;
+        assert_eq!(sort_keys(&a).to_string(), canonical);
+        assert_eq!(sort_keys(&b).to_string(), canonical);
+        assert_eq!(
+            generate_uuid5(&a, "Article").unwrap(),
+            generate_uuid5(canonical, "Article").unwrap()
+        );
+        assert_eq!(
+            generate_uuid5(&b, "Article").unwrap(),
+            generate_uuid5(canonical, "Article").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_object_builder_generated_id() {
+        let a = Object::builder("Article", serde_json::json!({"title": "A", "views": 1}))
+            .with_generated_id_from(&["title"])
+            .build();
+        let b = Object::builder("Article", serde_json::json!({"title": "A", "views": 2}))
+            .with_generated_id_from(&["title"])
+            .build();
+        let c = Object::builder("Article", serde_json::json!({"title": "A", "views": 2}))
+            .with_generated_id()
+            .build();
+        assert_eq!(a.id, b.id);
+        assert_ne!(b.id, c.id);
+    }
+
+    #[test]
+    fn test_object_references() {
+        let object = Object::builder(
+            "JeopardyQuestion",
+            serde_json::json!({
+                "hasCategory": [{
+                    "beacon": format!("weaviate://localhost/JeopardyCategory/{ID}"),
+                    "href": format!("/v1/objects/JeopardyCategory/{ID}")
+                }]
+            }),
+        )
+        .build();
+        let references = object.references("hasCategory").unwrap();
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].beacon.id.to_string(), ID);
+        assert!(object.references("missing").unwrap().is_empty());
+    }
+}
@@ -14,7 +14,8 @@
 //! There are also some places I need to return an error from which I am yet to do.
 //!
 //! I've also not had a chance to test a lot of the functionality, so lots will be broken like the
-//! near_text or near_image as I have not implemented the `encoding` functionality yet.
+//! near_text filter. The near<media> filters can be built with `NearMedia`, which handles the
+//! base64 `encoding` of the media.
 
 mod raw;
 pub use raw::*;
@@ -22,11 +23,38 @@ pub use raw::*;
 mod aggregate;
 pub use aggregate::*;
 
+mod aggregation;
+pub use aggregation::*;
+
 mod explore;
 pub use explore::*;
 
 mod get;
 pub use get::*;
 
+mod hybrid;
+pub use hybrid::*;
+
+mod near;
+pub use near::*;
+
+mod near_media;
+pub use near_media::*;
+
+mod rerank;
+pub use rerank::*;
+
+mod group_by;
+pub use group_by::*;
+
+mod graphql_value;
+pub use graphql_value::*;
+
+mod multi;
+pub use multi::*;
+
 mod response;
 pub use response::*;
+
+mod validation;
+pub use validation::ValidationProblem;
@@ -1,5 +1,10 @@
 use serde_json::json;
 
+use super::validation::{extract_paths, PathUsage, Validator};
+use super::{AggregateField, NearMedia};
+use crate::error::QueryError;
+use crate::models::schema::Classes;
+
 /// AggregatorQuery struct to hold an Aggregate query.
 #[derive(Debug)]
 pub struct AggregateQuery {
@@ -7,6 +12,8 @@ pub struct AggregateQuery {
     pub object_limit: Option<u32>,
     pub meta_count: Option<bool>,
     pub fields: Option<Vec<String>>,
+    pub aggregations: Option<Vec<AggregateField>>,
+    pub grouped_by: Option<bool>,
     pub where_clause: Option<String>,
     pub group_by: Option<String>,
     pub near: Option<String>,
@@ -29,6 +36,8 @@ impl AggregateQuery {
             object_limit: None,
             meta_count: None,
             fields: None,
+            aggregations: None,
+            grouped_by: None,
             where_clause: None,
             group_by: None,
             near: None,
@@ -84,6 +93,26 @@ impl AggregateQuery {
         self
     }
 
+    /// Appends the specified typed aggregations in the aggregate query body.
+    ///
+    /// The results can be read back with the matching accessors on `AggregateResult`, for
+    /// example through `Query::aggregate_results`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{AggregateField, AggregateQuery};
+    ///
+    /// let query_builder = AggregateQuery::new("Article")
+    ///     .with_aggregations(vec![
+    ///         AggregateField::int("wordCount"),
+    ///         AggregateField::text("title").with_top_occurrences_limit(5),
+    ///     ]);
+    /// ```
+    pub fn with_aggregations(mut self, aggregations: Vec<AggregateField>) -> Self {
+        self.aggregations = Some(aggregations);
+        self
+    }
+
     /// Set the `where` filter in the aggregate query.
     ///
     /// # Example -> todo
@@ -111,6 +140,26 @@ impl AggregateQuery {
         self
     }
 
+    /// Group the aggregate query results by the given property path.
+    ///
+    /// This sets the `groupBy` filter and adds `groupedBy { value path }` to the body of the
+    /// query, so that each `AggregateResult` contains the value it was grouped by.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{AggregateField, AggregateQuery};
+    ///
+    /// let query_builder = AggregateQuery::new("Article")
+    ///     .with_group_by(vec!["inPublication"])
+    ///     .with_meta_count()
+    ///     .with_aggregations(vec![AggregateField::int("wordCount")]);
+    /// ```
+    pub fn with_group_by(mut self, path: Vec<&str>) -> Self {
+        self.group_by = Some(serde_json::Value::from(path).to_string());
+        self.grouped_by = Some(true);
+        self
+    }
+
     /// Set the `nearText` filter in the aggregate query. This filter can be used with text modules
     /// (text2vec).
     ///
@@ -124,7 +173,7 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_text.into());
+        self.near = Some(format!("nearText: {}", near_text.into()));
         self
     }
 
@@ -137,7 +186,7 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_vector.into());
+        self.near = Some(format!("nearVector: {}", near_vector.into()));
         self
     }
 
@@ -150,7 +199,7 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_object.into());
+        self.near = Some(format!("nearObject: {}", near_object.into()));
         self
     }
 
@@ -163,7 +212,7 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_image.into());
+        self.near = Some(format!("nearImage: {}", near_image.into()));
         self
     }
 
@@ -176,7 +225,7 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_audio.into());
+        self.near = Some(format!("nearAudio: {}", near_audio.into()));
         self
     }
 
@@ -189,7 +238,7 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_video.into());
+        self.near = Some(format!("nearVideo: {}", near_video.into()));
         self
     }
 
@@ -202,7 +251,7 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_depth.into());
+        self.near = Some(format!("nearDepth: {}", near_depth.into()));
         self
     }
 
@@ -215,7 +264,7 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_thermal.into());
+        self.near = Some(format!("nearThermal: {}", near_thermal.into()));
         self
     }
 
@@ -228,7 +277,30 @@ impl AggregateQuery {
         if self.near.is_some() {
             // raise an error here, can only have one near filter
         }
-        self.near = Some(near_imu.into());
+        self.near = Some(format!("nearIMU: {}", near_imu.into()));
+        self
+    }
+
+    /// Set one of the `near<media>` filters in the aggregate query, such as `nearImage`.
+    ///
+    /// The filter that is set depends on the `MediaType` of the `NearMedia`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{AggregateQuery, MediaType, NearMedia};
+    ///
+    /// let query_builder = AggregateQuery::new("Dog")
+    ///     .with_near_media(NearMedia::new(MediaType::Image, b"raw image bytes").with_distance(0.2))
+    ///     .with_meta_count();
+    /// ```
+    pub fn with_near_media(mut self, near_media: NearMedia) -> Self {
+        if self.near.is_some() {
+            // raise an error here, can only have one near filter
+        }
+        self.near = Some(format!(
+            "{}: {near_media}",
+            near_media.media_type.operator()
+        ));
         self
     }
 
@@ -258,6 +330,25 @@ impl AggregateQuery {
         self
     }
 
+    /// Get the classes the query depends on, which are the class of the query and any classes
+    /// reached through reference paths in the `where` filter, such as `["inPublication",
+    /// "Publication", "name"]`.
+    pub(crate) fn classes(&self) -> Vec<String> {
+        let mut classes = vec![self.class_name.clone()];
+        if let Some(where_clause) = &self.where_clause {
+            for path in extract_paths(where_clause) {
+                // Reference paths alternate between properties and classes, ending in a property
+                let references = &path[..path.len().saturating_sub(1)];
+                for class_name in references.iter().skip(1).step_by(2) {
+                    if !classes.contains(class_name) {
+                        classes.push(class_name.clone());
+                    }
+                }
+            }
+        }
+        classes
+    }
+
     /// Check if the query contains a filter.
     fn contains_filter(&self) -> bool {
         self.where_clause.is_some()
@@ -268,17 +359,63 @@ impl AggregateQuery {
             || self.limit.is_some()
     }
 
+    /// Validate the query against a schema, such as one retrieved with `Schema::get`.
+    ///
+    /// This checks that the class and the aggregated properties exist, that the properties used
+    /// in the `where` filter are indexed, and that the `groupBy` property exists.
+    ///
+    /// # Errors
+    /// A `QueryError::Validation` containing all of the problems found.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{AggregateField, AggregateQuery};
+    /// use weaviate_community::models::schema::{Class, Classes};
+    ///
+    /// let schema = Classes::new(vec![Class::builder("Article").build()]);
+    ///
+    /// let query = AggregateQuery::new("Article")
+    ///     .with_aggregations(vec![AggregateField::int("wordCount")]);
+    /// assert!(query.validate(&schema).is_err());
+    /// ```
+    pub fn validate(&self, schema: &Classes) -> Result<(), QueryError> {
+        let mut validator = Validator::new(schema);
+        if let Some(class) = validator.class(&self.class_name) {
+            for aggregation in self.aggregations.iter().flatten() {
+                validator.property(class, &aggregation.property);
+            }
+            if let Some(fields) = &self.fields {
+                validator.aggregate_fields(class, &fields.join(" "));
+            }
+            if let Some(where_clause) = &self.where_clause {
+                validator.paths_in(class, where_clause, PathUsage::Filter);
+            }
+            if let Some(group_by) = &self.group_by {
+                validator.group_by_in(class, group_by);
+            }
+        }
+        validator.finish()
+    }
+
     /// Convert the `AggregateQuery` into a `serde_json::Value` payload.
     pub fn as_payload(&self) -> serde_json::Value {
         json!({ "query": self.to_string() })
     }
 }
 
-impl std::fmt::Display for AggregateQuery {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{{")?;
-        writeln!(f, "  Aggregate {{")?;
-        writeln!(f, "    {}", self.class_name)?;
+impl AggregateQuery {
+    /// Write the class selection of the query, optionally prefixed with a GraphQL alias.
+    ///
+    /// This is the part of the query inside of `Aggregate { ... }`.
+    pub(crate) fn write_class(
+        &self,
+        f: &mut dyn std::fmt::Write,
+        alias: Option<&str>,
+    ) -> std::fmt::Result {
+        match alias {
+            Some(alias) => writeln!(f, "    {alias}: {}", self.class_name)?,
+            None => writeln!(f, "    {}", self.class_name)?,
+        }
 
         if self.contains_filter() {
             writeln!(f, "    (")?;
@@ -289,13 +426,17 @@ impl std::fmt::Display for AggregateQuery {
                 writeln!(f, "      groupBy: {group_by}")?;
             }
             if let Some(near) = &self.near {
-                writeln!(f, "      near: {near}")?;
+                writeln!(f, "      {near}")?;
             }
             if let Some(object_limit) = &self.object_limit {
                 writeln!(f, "      objectLimit: {object_limit}")?;
             }
             if let Some(tenant) = &self.tenant {
-                writeln!(f, "      tenant: {tenant}")?;
+                writeln!(
+                    f,
+                    "      tenant: {}",
+                    serde_json::Value::from(tenant.as_str())
+                )?;
             }
             if let Some(limit) = &self.limit {
                 writeln!(f, "      limit: {limit}")?;
@@ -307,10 +448,24 @@ impl std::fmt::Display for AggregateQuery {
         if self.meta_count.is_some() {
             writeln!(f, "      meta {{ count }}")?;
         }
+        if self.grouped_by.is_some() {
+            writeln!(f, "      groupedBy {{ value path }}")?;
+        }
+        for aggregation in self.aggregations.iter().flatten() {
+            writeln!(f, "      {aggregation}")?;
+        }
         if let Some(fields) = &self.fields {
             writeln!(f, "      {}", fields.join(" "))?;
         }
-        writeln!(f, "    }}")?;
+        writeln!(f, "    }}")
+    }
+}
+
+impl std::fmt::Display for AggregateQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "  Aggregate {{")?;
+        self.write_class(f, None)?;
         writeln!(f, "  }}")?;
         write!(f, "}}")
     }
@@ -321,6 +476,7 @@ mod tests {
     use indoc::indoc;
 
     use super::*;
+    use crate::models::query::MediaType;
 
     #[test]
     fn test_aggregate_query() {
@@ -346,4 +502,75 @@ mod tests {
 
         assert_eq!(query.to_string(), expected);
     }
+
+    #[test]
+    fn test_aggregate_query_classes() {
+        let query = AggregateQuery::new("JeopardyQuestion")
+            .with_meta_count()
+            .with_where(
+                r#"{operator: Or, operands: [
+                    {path: ["hasCategory", "JeopardyCategory", "title"], operator: Equal, valueText: "Animals"},
+                    {path: ["points"], operator: GreaterThan, valueInt: 100}
+                ]}"#,
+            );
+        assert_eq!(
+            query.classes(),
+            vec!["JeopardyQuestion", "JeopardyCategory"]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_query_typed() {
+        let query = AggregateQuery::new("Article")
+            .with_group_by(vec!["inPublication"])
+            .with_meta_count()
+            .with_aggregations(vec![
+                AggregateField::int("wordCount"),
+                AggregateField::boolean("isAccessible"),
+            ]);
+
+        let expected = indoc! {r#"
+            {
+              Aggregate {
+                Article
+                (
+                  groupBy: ["inPublication"]
+                )
+                {
+                  meta { count }
+                  groupedBy { value path }
+                  wordCount { count type mean median mode sum minimum maximum }
+                  isAccessible { count type totalTrue totalFalse percentageTrue percentageFalse }
+                }
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+    }
+
+    #[test]
+    fn test_aggregate_query_near_media() {
+        let query = AggregateQuery::new("Dog")
+            .with_near_media(NearMedia::new(MediaType::Image, b"hello").with_certainty(0.7))
+            .with_object_limit(5)
+            .with_meta_count();
+
+        let expected = indoc! {r#"
+            {
+              Aggregate {
+                Dog
+                (
+                  nearImage: {image: "aGVsbG8=" certainty: 0.7}
+                  objectLimit: 5
+                )
+                {
+                  meta { count }
+                }
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+    }
 }
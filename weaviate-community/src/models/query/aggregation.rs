@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::GroupedBy;
+
+/// The kind of aggregation to perform on a property, based on its data type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AggregationKind {
+    Text { top_occurrences_limit: Option<u32> },
+    Number,
+    Int,
+    Boolean,
+    Date,
+    Reference,
+}
+
+/// AggregateField struct to hold a typed property aggregation in an Aggregate query.
+///
+/// Each kind of aggregation selects all of the metrics Weaviate provides for the data type, and
+/// the results can be read back with the matching accessor on `AggregateResult`.
+///
+/// More information on the available aggregations can be found [here](https://weaviate.io/developers/weaviate/api/graphql/aggregate)
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateField {
+    pub property: String,
+    pub kind: AggregationKind,
+}
+
+impl AggregateField {
+    /// Aggregate a `text` property, selecting `count`, `type` and `topOccurrences`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::AggregateField;
+    ///
+    /// let field = AggregateField::text("title").with_top_occurrences_limit(5);
+    /// ```
+    pub fn text(property: impl Into<String>) -> Self {
+        Self::new(
+            property,
+            AggregationKind::Text {
+                top_occurrences_limit: None,
+            },
+        )
+    }
+
+    /// Aggregate a `number` property, selecting `count`, `type`, `mean`, `median`, `mode`, `sum`,
+    /// `minimum` and `maximum`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::AggregateField;
+    ///
+    /// let field = AggregateField::number("price");
+    /// ```
+    pub fn number(property: impl Into<String>) -> Self {
+        Self::new(property, AggregationKind::Number)
+    }
+
+    /// Aggregate an `int` property, selecting the same metrics as `AggregateField::number`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::AggregateField;
+    ///
+    /// let field = AggregateField::int("wordCount");
+    /// ```
+    pub fn int(property: impl Into<String>) -> Self {
+        Self::new(property, AggregationKind::Int)
+    }
+
+    /// Aggregate a `boolean` property, selecting `count`, `type`, `totalTrue`, `totalFalse`,
+    /// `percentageTrue` and `percentageFalse`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::AggregateField;
+    ///
+    /// let field = AggregateField::boolean("isAccessible");
+    /// ```
+    pub fn boolean(property: impl Into<String>) -> Self {
+        Self::new(property, AggregationKind::Boolean)
+    }
+
+    /// Aggregate a `date` property, selecting `count`, `minimum`, `maximum`, `median` and `mode`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::AggregateField;
+    ///
+    /// let field = AggregateField::date("publicationDate");
+    /// ```
+    pub fn date(property: impl Into<String>) -> Self {
+        Self::new(property, AggregationKind::Date)
+    }
+
+    /// Aggregate a cross-reference property, selecting `type` and `pointingTo`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::AggregateField;
+    ///
+    /// let field = AggregateField::reference("inPublication");
+    /// ```
+    pub fn reference(property: impl Into<String>) -> Self {
+        Self::new(property, AggregationKind::Reference)
+    }
+
+    /// Set the `limit` of the `topOccurrences` of a text aggregation.
+    ///
+    /// Has no effect on aggregations of other kinds.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::AggregateField;
+    ///
+    /// let field = AggregateField::text("title").with_top_occurrences_limit(5);
+    /// ```
+    pub fn with_top_occurrences_limit(mut self, limit: u32) -> Self {
+        if let AggregationKind::Text {
+            top_occurrences_limit,
+        } = &mut self.kind
+        {
+            *top_occurrences_limit = Some(limit);
+        }
+        self
+    }
+
+    fn new(property: impl Into<String>, kind: AggregationKind) -> Self {
+        AggregateField {
+            property: property.into(),
+            kind,
+        }
+    }
+}
+
+impl std::fmt::Display for AggregateField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {{ ", self.property)?;
+        match &self.kind {
+            AggregationKind::Text {
+                top_occurrences_limit,
+            } => {
+                write!(f, "count type topOccurrences")?;
+                if let Some(limit) = top_occurrences_limit {
+                    write!(f, "(limit: {limit})")?;
+                }
+                write!(f, " {{ value occurs }}")?;
+            }
+            AggregationKind::Number | AggregationKind::Int => {
+                write!(f, "count type mean median mode sum minimum maximum")?;
+            }
+            AggregationKind::Boolean => {
+                write!(
+                    f,
+                    "count type totalTrue totalFalse percentageTrue percentageFalse"
+                )?;
+            }
+            AggregationKind::Date => {
+                write!(f, "count minimum maximum median mode")?;
+            }
+            AggregationKind::Reference => {
+                write!(f, "type pointingTo")?;
+            }
+        }
+        write!(f, " }}")
+    }
+}
+
+/// A single result of an Aggregate query.
+///
+/// When the query is grouped, there is one result per group and `grouped_by` is populated.
+///
+/// You shouldn't need to ever create this struct - it is what each item of an Aggregate
+/// response is deserialized into.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub meta: Option<MetaAggregation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub grouped_by: Option<GroupedBy>,
+    #[serde(flatten)]
+    pub properties: HashMap<String, serde_json::Value>,
+}
+
+impl AggregateResult {
+    /// Get the text aggregation of a property.
+    pub fn text(&self, property: &str) -> Option<TextAggregation> {
+        self.property(property)
+    }
+
+    /// Get the number or int aggregation of a property.
+    pub fn number(&self, property: &str) -> Option<NumberAggregation> {
+        self.property(property)
+    }
+
+    /// Get the boolean aggregation of a property.
+    pub fn boolean(&self, property: &str) -> Option<BooleanAggregation> {
+        self.property(property)
+    }
+
+    /// Get the date aggregation of a property.
+    pub fn date(&self, property: &str) -> Option<DateAggregation> {
+        self.property(property)
+    }
+
+    /// Get the cross-reference aggregation of a property.
+    pub fn reference(&self, property: &str) -> Option<ReferenceAggregation> {
+        self.property(property)
+    }
+
+    fn property<T: DeserializeOwned>(&self, property: &str) -> Option<T> {
+        let value = self.properties.get(property)?;
+        serde_json::from_value(value.clone()).ok()
+    }
+}
+
+/// The `meta { count }` of an Aggregate result.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MetaAggregation {
+    pub count: u64,
+}
+
+/// The aggregation of a `text` property.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TextAggregation {
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub data_type: Option<String>,
+    #[serde(default)]
+    pub top_occurrences: Option<Vec<TopOccurrence>>,
+}
+
+/// A value and how often it occurs in a `text` property.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TopOccurrence {
+    pub value: String,
+    pub occurs: u64,
+}
+
+/// The aggregation of a `number` or `int` property.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NumberAggregation {
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub data_type: Option<String>,
+    #[serde(default)]
+    pub mean: Option<f64>,
+    #[serde(default)]
+    pub median: Option<f64>,
+    #[serde(default)]
+    pub mode: Option<f64>,
+    #[serde(default)]
+    pub sum: Option<f64>,
+    #[serde(default)]
+    pub minimum: Option<f64>,
+    #[serde(default)]
+    pub maximum: Option<f64>,
+}
+
+/// The aggregation of a `boolean` property.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BooleanAggregation {
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub data_type: Option<String>,
+    #[serde(default)]
+    pub total_true: Option<u64>,
+    #[serde(default)]
+    pub total_false: Option<u64>,
+    #[serde(default)]
+    pub percentage_true: Option<f64>,
+    #[serde(default)]
+    pub percentage_false: Option<f64>,
+}
+
+/// The aggregation of a `date` property.
+///
+/// The dates are returned as RFC 3339 formatted strings.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DateAggregation {
+    #[serde(default)]
+    pub count: Option<u64>,
+    #[serde(default)]
+    pub minimum: Option<String>,
+    #[serde(default)]
+    pub maximum: Option<String>,
+    #[serde(default)]
+    pub median: Option<String>,
+    #[serde(default)]
+    pub mode: Option<String>,
+}
+
+/// The aggregation of a cross-reference property.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceAggregation {
+    #[serde(rename = "type")]
+    #[serde(default)]
+    pub data_type: Option<String>,
+    #[serde(default)]
+    pub pointing_to: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_field_display() {
+        assert_eq!(
+            AggregateField::text("title")
+                .with_top_occurrences_limit(3)
+                .to_string(),
+            "title { count type topOccurrences(limit: 3) { value occurs } }"
+        );
+        assert_eq!(
+            AggregateField::int("wordCount").to_string(),
+            "wordCount { count type mean median mode sum minimum maximum }"
+        );
+        assert_eq!(
+            AggregateField::reference("inPublication").to_string(),
+            "inPublication { type pointingTo }"
+        );
+    }
+
+    #[test]
+    fn test_aggregate_result_deserialize() {
+        let result: AggregateResult = serde_json::from_value(serde_json::json!({
+            "meta": { "count": 4403 },
+            "groupedBy": { "value": "Publication", "path": ["inPublication"] },
+            "inPublication": { "pointingTo": ["Publication"], "type": "cref" },
+            "title": {
+                "count": 4403,
+                "type": "text",
+                "topOccurrences": [{ "value": "News", "occurs": 12 }]
+            },
+            "wordCount": {
+                "count": 4403,
+                "maximum": 16852,
+                "mean": 966.0113558937088,
+                "median": 680,
+                "minimum": 109,
+                "mode": 575,
+                "sum": 4253348,
+                "type": "int"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(result.meta.as_ref().unwrap().count, 4403);
+        assert_eq!(result.grouped_by.as_ref().unwrap().value, "Publication");
+        assert_eq!(result.number("wordCount").unwrap().maximum, Some(16852.0));
+        assert_eq!(
+            result.text("title").unwrap().top_occurrences.unwrap()[0].occurs,
+            12
+        );
+        assert_eq!(
+            result.reference("inPublication").unwrap().pointing_to,
+            Some(vec!["Publication".into()])
+        );
+        assert!(result.boolean("missing").is_none());
+    }
+}
@@ -1,13 +1,18 @@
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use uuid::Uuid;
 
+use super::{NearText, NearVector};
 use crate::error::QueryError;
+use crate::models::objects::Beacon;
 
 /// ExploreQuery struct to hold an Explore query.
 #[derive(Debug)]
 pub struct ExploreQuery {
     limit: Option<u32>,
-    near_text: Option<String>,
-    near_vector: Option<String>,
+    offset: Option<u32>,
+    near_text: Option<NearText>,
+    near_vector: Option<NearVector>,
     fields: Option<Vec<String>>,
 }
 
@@ -25,6 +30,7 @@ impl ExploreQuery {
     pub fn new() -> Self {
         Self {
             limit: None,
+            offset: None,
             near_text: None,
             near_vector: None,
             fields: None,
@@ -60,29 +66,59 @@ impl ExploreQuery {
         self
     }
 
+    /// Sets the `offset` in the explore query filters.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::ExploreQuery;
+    ///
+    /// let query_builder = ExploreQuery::new()
+    ///     .with_limit(10)
+    ///     .with_offset(10);
+    /// ```
+    pub fn with_offset(mut self, offset: u32) -> ExploreQuery {
+        self.offset = Some(offset);
+        self
+    }
+
     /// Sets the `nearText` value in the explore query filters.
     ///
-    /// One of either `with_near_text` or `with_near_vector` must be set in the query at point of
-    /// build.
+    /// Exactly one of either `with_near_text` or `with_near_vector` must be set in the query.
     ///
     /// # Example
     /// ```
+    /// use weaviate_community::models::query::{ExploreQuery, NearText};
+    ///
+    /// let query_builder = ExploreQuery::new()
+    ///     .with_near_text(NearText::new(vec!["fashion"]).with_certainty(0.7));
     /// ```
-    pub fn with_near_text(mut self, near_text: impl Into<String>) -> ExploreQuery {
-        self.near_text = Some(near_text.into());
+    pub fn with_near_text(mut self, near_text: NearText) -> ExploreQuery {
+        self.near_text = Some(near_text);
         self
     }
 
     /// Sets the `nearVector` value in the explore query filters.
     ///
-    /// One of either `with_near_text` or `with_near_vector` must be set in the query at point of
-    /// build.
+    /// Exactly one of either `with_near_text` or `with_near_vector` must be set in the query.
     ///
     /// # Example
     /// ```
+    /// use weaviate_community::models::query::{ExploreQuery, NearVector};
+    ///
+    /// let query_builder = ExploreQuery::new()
+    ///     .with_near_vector(NearVector::new(vec![-0.36840257, 0.13973749, -0.28994447]));
     /// ```
-    pub fn with_near_vector(mut self, near_vector: impl Into<String>) -> ExploreQuery {
-        self.near_vector = Some(near_vector.into());
+    pub fn with_near_vector(mut self, near_vector: NearVector) -> ExploreQuery {
+        self.near_vector = Some(near_vector);
+        self
+    }
+
+    /// Set the fields to the ones that are deserialized into an `ExploreResult`, if no fields
+    /// were set.
+    pub(crate) fn with_default_fields(mut self) -> ExploreQuery {
+        if self.fields.is_none() {
+            self = self.with_fields(vec!["beacon", "className", "distance"]);
+        }
         self
     }
 
@@ -97,6 +133,15 @@ impl ExploreQuery {
                 "ExploreQuery must contain either nearText or nearVector".into(),
             ));
         }
+        if self.near_text.is_some() && self.near_vector.is_some() {
+            return Err(QueryError::InvalidCombination(&["nearText", "nearVector"]));
+        }
+        if let Some(near_text) = &self.near_text {
+            near_text.check()?;
+        }
+        if let Some(near_vector) = &self.near_vector {
+            near_vector.check()?;
+        }
 
         Ok(json!({ "query": self.to_string() }))
     }
@@ -120,6 +165,9 @@ impl std::fmt::Display for ExploreQuery {
             if let Some(limit) = &self.limit {
                 writeln!(f, "    limit: {limit}")?;
             }
+            if let Some(offset) = &self.offset {
+                writeln!(f, "    offset: {offset}")?;
+            }
             if let Some(near_text) = &self.near_text {
                 writeln!(f, "    nearText: {near_text}")?;
             }
@@ -138,3 +186,94 @@ impl std::fmt::Display for ExploreQuery {
         write!(f, "}}")
     }
 }
+
+/// A single result of an Explore query.
+///
+/// `certainty` is only populated if it was requested with `ExploreQuery::with_fields`, and the
+/// class of the result uses the cosine distance metric.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExploreResult {
+    pub beacon: Beacon,
+    pub class_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub certainty: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub distance: Option<f64>,
+}
+
+impl ExploreResult {
+    /// Get the UUID of the object the result refers to.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::ExploreResult;
+    ///
+    /// let result: ExploreResult = serde_json::from_value(serde_json::json!({
+    ///     "beacon": "weaviate://localhost/Publication/7e9b9ffe-e645-302d-9d94-517670623b35",
+    ///     "className": "Publication",
+    /// })).unwrap();
+    /// assert_eq!(result.id().to_string(), "7e9b9ffe-e645-302d-9d94-517670623b35");
+    /// ```
+    pub fn id(&self) -> Uuid {
+        self.beacon.id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_explore_query() {
+        let query = ExploreQuery::new()
+            .with_limit(2)
+            .with_offset(4)
+            .with_near_text(NearText::new(vec!["fashion"]).with_distance(0.3))
+            .with_default_fields();
+
+        let expected = indoc! {r#"
+            {
+              Explore
+              (
+                limit: 2
+                offset: 4
+                nearText: {concepts: ["fashion"], distance: 0.3}
+              )
+              {
+                beacon className distance
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+        assert!(query.as_payload().is_ok());
+
+        let query = query.with_near_vector(NearVector::new(vec![0.1]));
+        assert!(matches!(
+            query.as_payload(),
+            Err(QueryError::InvalidCombination(_))
+        ));
+    }
+
+    #[test]
+    fn test_explore_result() {
+        let result: ExploreResult = serde_json::from_value(serde_json::json!({
+            "beacon": "weaviate://localhost/7e9b9ffe-e645-302d-9d94-517670623b35",
+            "certainty": 0.975523,
+            "className": "Publication"
+        }))
+        .unwrap();
+        assert_eq!(
+            result.id(),
+            Uuid::parse_str("7e9b9ffe-e645-302d-9d94-517670623b35").unwrap()
+        );
+        assert!(result.beacon.class_name.is_none());
+        assert_eq!(result.certainty, Some(0.975523));
+        assert!(result.distance.is_none());
+    }
+}
@@ -1,6 +1,12 @@
 use serde_json::json;
 use uuid::Uuid;
 
+use super::validation::{extract_paths, PathUsage, Validator};
+use super::{GroupBy, Hybrid, MediaType, NearMedia, Rerank};
+use crate::error::QueryError;
+use crate::models::objects::{ConsistencyLevel, Sort};
+use crate::models::schema::Classes;
+
 /// GetQuery struct to hold a Get query.
 #[derive(Debug)]
 pub struct GetQuery {
@@ -20,13 +26,15 @@ pub struct GetQuery {
     pub near_thermal: Option<String>,
     pub near_imu: Option<String>,
     pub near_depth: Option<String>,
-    pub sort: Option<String>,
+    pub sort: Option<Vec<Sort>>,
     pub bm25: Option<String>,
-    pub hybrid: Option<String>,
-    pub group_by: Option<String>,
+    pub hybrid: Option<Hybrid>,
+    pub group_by: Option<GroupBy>,
     pub tenant: Option<String>,
+    pub consistency_level: Option<ConsistencyLevel>,
     pub autocut: Option<u32>,
     pub ask: Option<String>,
+    pub rerank: Option<Rerank>,
 }
 
 impl GetQuery {
@@ -49,6 +57,7 @@ impl GetQuery {
             offset: None,
             additional: None,
             tenant: None,
+            consistency_level: None,
             autocut: None,
             after: None,
             sort: None,
@@ -66,6 +75,7 @@ impl GetQuery {
             bm25: None,
             ask: None,
             group_by: None,
+            rerank: None,
         }
     }
 
@@ -142,6 +152,25 @@ impl GetQuery {
         self
     }
 
+    /// Specify the `consistencyLevel` of the get query.
+    ///
+    /// In a replicated cluster, the consistency level determines how many replicas must respond
+    /// before the results are returned. Reading with `ConsistencyLevel::QUORUM` or
+    /// `ConsistencyLevel::ALL` after writing with the same level guarantees the writes are seen.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::objects::ConsistencyLevel;
+    /// use weaviate_community::models::query::GetQuery;
+    ///
+    /// let query_builder = GetQuery::new("JeopardyQuestion", vec!["answer"])
+    ///     .with_consistency_level(ConsistencyLevel::QUORUM);
+    /// ```
+    pub fn with_consistency_level(mut self, consistency_level: ConsistencyLevel) -> GetQuery {
+        self.consistency_level = Some(consistency_level);
+        self
+    }
+
     /// Specify the `autocut` search filter in the get query.
     ///
     /// The `autocut` filter is an argument that can be added to class objects retrieved by the
@@ -151,10 +180,10 @@ impl GetQuery {
     ///
     /// # Example
     /// ```
-    /// use weaviate_community::models::query::GetQuery;
+    /// use weaviate_community::models::query::{GetQuery, Hybrid};
     ///
     /// let query_builder = GetQuery::new("JeopardyQuestion", vec!["question", "answer"])
-    ///     .with_hybrid("{query: \"food\"}")
+    ///     .with_hybrid(Hybrid::new("food"))
     ///     .with_autocut(1);
     /// ```
     pub fn with_autocut(mut self, autocut: u32) -> GetQuery {
@@ -179,13 +208,24 @@ impl GetQuery {
     /// Specify the `sort` search filter in the get query.
     ///
     /// Any primitive property types can be sorted, such as `text`, `string`, `number`, or `int`.
+    /// The objects are sorted by the first criterion, then by the following criteria for objects
+    /// that are equal.
     ///
     /// When a query has a natural order (e.g. because of a near<media> vector search), adding a
-    /// sort operator will override that order.
+    /// sort operator will override that order. Cannot be used with `after`.
     ///
     /// More on sorting in Weaviate can be found [here](https://weaviate.io/developers/weaviate/api/graphql/additional-operators#sorting)
-    pub fn with_sort(mut self, sort: impl Into<String>) -> GetQuery {
-        self.sort = Some(sort.into());
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::objects::Sort;
+    /// use weaviate_community::models::query::GetQuery;
+    ///
+    /// let query_builder = GetQuery::new("JeopardyQuestion", vec!["question", "points"])
+    ///     .with_sort(vec![Sort::property("points").descending(), Sort::creation_time()]);
+    /// ```
+    pub fn with_sort(mut self, sort: Vec<Sort>) -> GetQuery {
+        self.sort = Some(sort);
         self
     }
 
@@ -291,40 +331,74 @@ impl GetQuery {
         self
     }
 
+    /// Set one of the `near<media>` filters in the get query, such as `nearImage`.
+    ///
+    /// The filter that is set depends on the `MediaType` of the `NearMedia`, and replaces any
+    /// value previously set with the matching `with_near_<media>` method.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{GetQuery, MediaType, NearMedia};
+    ///
+    /// let query_builder = GetQuery::new("Dog", vec!["breed"])
+    ///     .with_near_media(NearMedia::new(MediaType::Image, b"raw image bytes").with_certainty(0.7))
+    ///     .with_limit(1);
+    /// ```
+    pub fn with_near_media(mut self, near_media: NearMedia) -> GetQuery {
+        let filter = Some(near_media.to_string());
+        match near_media.media_type {
+            MediaType::Image => self.near_image = filter,
+            MediaType::Audio => self.near_audio = filter,
+            MediaType::Video => self.near_video = filter,
+            MediaType::Thermal => self.near_thermal = filter,
+            MediaType::Depth => self.near_depth = filter,
+            MediaType::IMU => self.near_imu = filter,
+        }
+        self
+    }
+
     /// Specify the `hybrid` search filter in the get query.
     ///
     /// The `hybrid` operator produces results based on a weighted combination of results from a
-    /// keyword (bm25) search and a vector (near<media>) search.
+    /// keyword (bm25) search and a vector (near<media>) search. Request `score` and
+    /// `explainScore` in the `_additional` properties to see how each result was scored.
     ///
     /// # Example
     /// ```
-    /// use weaviate_community::models::query::GetQuery;
+    /// use weaviate_community::models::query::{FusionType, GetQuery, Hybrid};
     ///
     /// let query_builder = GetQuery::new("JeopardyQuestion", vec!["question", "answer"])
-    ///     .with_hybrid("{query: \"food\"}")
+    ///     .with_hybrid(
+    ///         Hybrid::new("food")
+    ///             .with_alpha(0.5)
+    ///             .with_fusion_type(FusionType::RelativeScoreFusion)
+    ///     )
+    ///     .with_additional(vec!["score", "explainScore"])
     ///     .with_limit(3);
     /// ```
     ///
     /// This will generate the following GetQuery:
     /// ```text
-    /// GetQuery {
-    ///   query: "{
-    ///     Get {
-    ///       JeopardyQuestion
-    ///       (
-    ///         limit: 3
-    ///         hybrid: {query: "food"]
-    ///       )
-    ///       {
-    ///         question
-    ///         answer
+    /// {
+    ///   Get {
+    ///     JeopardyQuestion
+    ///     (
+    ///       limit: 3
+    ///       hybrid: {query: "food", alpha: 0.5, fusionType: relativeScoreFusion}
+    ///     )
+    ///     {
+    ///       question
+    ///       answer
+    ///       _additional {
+    ///         score
+    ///         explainScore
     ///       }
     ///     }
     ///   }
     /// }
     /// ```
-    pub fn with_hybrid(mut self, hybrid: impl Into<String>) -> GetQuery {
-        self.hybrid = Some(hybrid.into());
+    pub fn with_hybrid(mut self, hybrid: Hybrid) -> GetQuery {
+        self.hybrid = Some(hybrid);
         self
     }
 
@@ -374,11 +448,40 @@ impl GetQuery {
     /// - The maximum number of groups, and
     /// - The maximum number of objects per group
     ///
+    /// The `groupBy` filter must be used together with a `near<media>` search. The groups are
+    /// returned in the `_additional { group { ... } }` properties, with the `hits` of each group
+    /// containing the properties of the query. The groups can be retrieved directly with
+    /// `Query::get_groups`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{GetQuery, GroupBy};
+    ///
+    /// let query_builder = GetQuery::new("Chunk", vec!["text"])
+    ///     .with_near_text("{concepts: [\"vector databases\"]}")
+    ///     .with_group_by(GroupBy::new(vec!["document"], 3, 2));
+    /// ```
+    pub fn with_group_by(mut self, group_by: GroupBy) -> GetQuery {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    /// Rerank the results of the get query using a reranker module.
+    ///
+    /// This adds `rerank(...) { score }` to the `_additional` properties of the query, and the
+    /// score can be read back through `AdditionalProperties::rerank_score`.
+    ///
     /// # Example
     /// ```
+    /// use weaviate_community::models::query::{GetQuery, Rerank};
+    ///
+    /// let query_builder = GetQuery::new("JeopardyQuestion", vec!["question", "answer"])
+    ///     .with_near_text("{concepts: [\"flying\"]}")
+    ///     .with_rerank(Rerank::new("answer").with_query("floating"))
+    ///     .with_limit(10);
     /// ```
-    pub fn with_group_by(mut self, group_by: impl Into<String>) -> GetQuery {
-        self.group_by = Some(group_by.into());
+    pub fn with_rerank(mut self, rerank: Rerank) -> GetQuery {
+        self.rerank = Some(rerank);
         self
     }
 
@@ -387,6 +490,38 @@ impl GetQuery {
         self
     }
 
+    /// Get the classes the query depends on, which are the class of the query, any classes
+    /// selected through reference properties with `... on ClassName`, and any classes reached
+    /// through reference paths in the `where` filter, such as `["inPublication", "Publication",
+    /// "name"]`.
+    pub(crate) fn classes(&self) -> Vec<String> {
+        let mut classes = vec![self.class_name.clone()];
+        let mut add = |class_name: &str| {
+            if !classes.iter().any(|class| class == class_name) {
+                classes.push(class_name.to_string());
+            }
+        };
+        for property in &self.properties {
+            let property = property.replace(['{', '}', '(', ')'], " ");
+            let tokens: Vec<&str> = property.split_whitespace().collect();
+            for window in tokens.windows(3) {
+                if let ["...", "on", class_name] = window {
+                    add(class_name);
+                }
+            }
+        }
+        if let Some(where_clause) = &self.where_clause {
+            for path in extract_paths(where_clause) {
+                // Reference paths alternate between properties and classes, ending in a property
+                let references = &path[..path.len().saturating_sub(1)];
+                for class_name in references.iter().skip(1).step_by(2) {
+                    add(class_name);
+                }
+            }
+        }
+        classes
+    }
+
     /// Check if the query contains a filter.
     fn contains_filter(&self) -> bool {
         self.limit.is_some()
@@ -394,28 +529,94 @@ impl GetQuery {
             || self.after.is_some()
             || self.autocut.is_some()
             || self.tenant.is_some()
+            || self.consistency_level.is_some()
             || self.where_clause.is_some()
             || self.near_text.is_some()
             || self.near_vector.is_some()
             || self.near_image.is_some()
             || self.near_object.is_some()
+            || self.near_video.is_some()
+            || self.near_audio.is_some()
+            || self.near_thermal.is_some()
+            || self.near_imu.is_some()
+            || self.near_depth.is_some()
             || self.hybrid.is_some()
             || self.bm25.is_some()
             || self.sort.is_some()
+            || self.group_by.is_some()
             || self.ask.is_some()
     }
 
+    /// Validate the query against a schema, such as one retrieved with `Schema::get`.
+    ///
+    /// This checks that the class and the selected properties exist, that reference fragments
+    /// target classes the reference points to, and that the properties used in the `where` and
+    /// `sort` filters are indexed. Validating is optional, and can be used to catch mistakes
+    /// before the query is sent to Weaviate.
+    ///
+    /// # Errors
+    /// A `QueryError::Validation` containing all of the problems found.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::GetQuery;
+    /// use weaviate_community::models::schema::{Class, Classes, Properties, Property};
+    ///
+    /// let schema = Classes::new(vec![Class::builder("JeopardyQuestion")
+    ///     .with_properties(Properties::new(vec![Property::builder("question", vec!["text"]).build()]))
+    ///     .build()]);
+    ///
+    /// let query = GetQuery::new("JeopardyQuestion", vec!["question", "answr"]);
+    /// assert!(query.validate(&schema).is_err());
+    /// ```
+    pub fn validate(&self, schema: &Classes) -> Result<(), QueryError> {
+        let mut validator = Validator::new(schema);
+        if let Some(class) = validator.class(&self.class_name) {
+            validator.selection(class, &self.properties.join(" "));
+            if let Some(where_clause) = &self.where_clause {
+                validator.paths_in(class, where_clause, PathUsage::Filter);
+            }
+            for sort in self.sort.iter().flatten() {
+                validator.path(class, &sort.path, PathUsage::Sort);
+            }
+            if let Some(group_by) = &self.group_by {
+                validator.path(class, &group_by.path, PathUsage::Group);
+            }
+        }
+        validator.finish()
+    }
+
+    /// Check that the query does not contain filters that cannot be used together.
+    pub(crate) fn check(&self) -> Result<(), QueryError> {
+        if self.after.is_some() && self.sort.is_some() {
+            return Err(QueryError::InvalidCombination(&["sort", "after"]));
+        }
+        Ok(())
+    }
+
     /// Convert the `GetQuery` into a `serde_json::Value` payload.
-    pub fn as_payload(&self) -> serde_json::Value {
-        json!({ "query": self.to_string() })
+    ///
+    /// # Errors
+    /// If the query contains filters that cannot be used together, such as `sort` and `after`.
+    pub fn as_payload(&self) -> Result<serde_json::Value, QueryError> {
+        self.check()?;
+        Ok(json!({ "query": self.to_string() }))
     }
 }
 
-impl std::fmt::Display for GetQuery {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{{")?;
-        writeln!(f, "  Get {{")?;
-        writeln!(f, "    {}", self.class_name)?;
+impl GetQuery {
+    /// Write the class selection of the query, optionally prefixed with a GraphQL alias.
+    ///
+    /// This is the part of the query inside of `Get { ... }`.
+    pub(crate) fn write_class(
+        &self,
+        f: &mut dyn std::fmt::Write,
+        alias: Option<&str>,
+    ) -> std::fmt::Result {
+        match alias {
+            Some(alias) => writeln!(f, "    {alias}: {}", self.class_name)?,
+            None => writeln!(f, "    {}", self.class_name)?,
+        }
 
         if self.contains_filter() {
             writeln!(f, "    (")?;
@@ -464,14 +665,25 @@ impl std::fmt::Display for GetQuery {
             if let Some(group_by) = &self.group_by {
                 writeln!(f, "      groupBy: {group_by}")?;
             }
-            if let Some(tenant) = &self.after {
-                writeln!(f, "      after: \"{tenant}\"")?;
+            if let Some(after) = &self.after {
+                writeln!(f, "      after: \"{after}\"")?;
+            }
+            if let Some(tenant) = &self.tenant {
+                writeln!(
+                    f,
+                    "      tenant: {}",
+                    serde_json::Value::from(tenant.as_str())
+                )?;
+            }
+            if let Some(consistency_level) = &self.consistency_level {
+                writeln!(f, "      consistencyLevel: {}", consistency_level.value())?;
             }
             if let Some(autocut) = &self.autocut {
                 writeln!(f, "      autocut: {autocut}")?;
             }
             if let Some(sort) = &self.sort {
-                writeln!(f, "      sort: {sort}")?;
+                let sort: Vec<String> = sort.iter().map(|s| s.to_string()).collect();
+                writeln!(f, "      sort: [{}]", sort.join(", "))?;
             }
             if let Some(ask) = &self.ask {
                 writeln!(f, "      ask: {ask}")?;
@@ -485,14 +697,40 @@ impl std::fmt::Display for GetQuery {
             writeln!(f, "      {property}")?;
         }
 
-        if let Some(additional) = &self.additional {
+        if self.additional.is_some() || self.rerank.is_some() || self.group_by.is_some() {
             writeln!(f, "      _additional {{")?;
-            for item in additional {
+            for item in self.additional.iter().flatten() {
                 writeln!(f, "        {item}")?;
             }
+            if let Some(rerank) = &self.rerank {
+                writeln!(f, "        {rerank}")?;
+            }
+            if self.group_by.is_some() {
+                writeln!(f, "        group {{")?;
+                writeln!(f, "          id")?;
+                writeln!(f, "          groupedBy {{ value path }}")?;
+                writeln!(f, "          count")?;
+                writeln!(f, "          maxDistance")?;
+                writeln!(f, "          minDistance")?;
+                writeln!(f, "          hits {{")?;
+                for property in &self.properties {
+                    writeln!(f, "            {property}")?;
+                }
+                writeln!(f, "            _additional {{ id distance }}")?;
+                writeln!(f, "          }}")?;
+                writeln!(f, "        }}")?;
+            }
             writeln!(f, "      }}")?;
         }
-        writeln!(f, "    }}")?;
+        writeln!(f, "    }}")
+    }
+}
+
+impl std::fmt::Display for GetQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{{")?;
+        writeln!(f, "  Get {{")?;
+        self.write_class(f, None)?;
         writeln!(f, "  }}")?;
         write!(f, "}}")
     }
@@ -503,6 +741,7 @@ mod tests {
     use indoc::indoc;
 
     use super::*;
+    use crate::models::query::FusionType;
 
     #[test]
     fn test_get_query() {
@@ -538,4 +777,194 @@ mod tests {
 
         assert_eq!(query.to_string(), expected);
     }
+
+    #[test]
+    fn test_get_query_rerank() {
+        let query = GetQuery::new("JeopardyQuestion", vec!["answer"])
+            .with_limit(10)
+            .with_additional(vec!["id"])
+            .with_rerank(Rerank::new("answer").with_query("floating"));
+
+        let expected = indoc! {r#"
+            {
+              Get {
+                JeopardyQuestion
+                (
+                  limit: 10
+                )
+                {
+                  answer
+                  _additional {
+                    id
+                    rerank(property: "answer" query: "floating") { score }
+                  }
+                }
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+    }
+
+    #[test]
+    fn test_get_query_group_by() {
+        let query = GetQuery::new("Chunk", vec!["text"])
+            .with_near_text("{concepts: [\"databases\"]}")
+            .with_group_by(GroupBy::new(vec!["document"], 3, 2));
+
+        let expected = indoc! {r#"
+            {
+              Get {
+                Chunk
+                (
+                  nearText: {concepts: ["databases"]}
+                  groupBy: {path: ["document"], groups: 3, objectsPerGroup: 2}
+                )
+                {
+                  text
+                  _additional {
+                    group {
+                      id
+                      groupedBy { value path }
+                      count
+                      maxDistance
+                      minDistance
+                      hits {
+                        text
+                        _additional { id distance }
+                      }
+                    }
+                  }
+                }
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+    }
+
+    #[test]
+    fn test_get_query_near_media() {
+        let query = GetQuery::new("Dog", vec!["breed"])
+            .with_near_media(NearMedia::new(MediaType::Audio, b"hello").with_distance(0.3));
+
+        let expected = indoc! {r#"
+            {
+              Get {
+                Dog
+                (
+                  nearAudio: {audio: "aGVsbG8=" distance: 0.3}
+                )
+                {
+                  breed
+                }
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+    }
+
+    #[test]
+    fn test_get_query_sort() {
+        let query = GetQuery::new("JeopardyQuestion", vec!["question"]).with_sort(vec![
+            Sort::property("points").descending(),
+            Sort::creation_time(),
+        ]);
+
+        let expected = indoc! {r#"
+            {
+              Get {
+                JeopardyQuestion
+                (
+                  sort: [{path: ["points"], order: desc}, {path: ["_creationTimeUnix"]}]
+                )
+                {
+                  question
+                }
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+        assert!(query.as_payload().is_ok());
+
+        let query = query.with_after(Uuid::nil());
+        assert!(matches!(
+            query.as_payload(),
+            Err(QueryError::InvalidCombination(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_query_hybrid() {
+        let query = GetQuery::new("JeopardyQuestion", vec!["question"])
+            .with_hybrid(
+                Hybrid::new("food")
+                    .with_alpha(0.5)
+                    .with_property_weight("question", 2)
+                    .with_fusion_type(FusionType::RelativeScoreFusion),
+            )
+            .with_additional(vec!["score", "explainScore"]);
+
+        let expected = indoc! {r#"
+            {
+              Get {
+                JeopardyQuestion
+                (
+                  hybrid: {query: "food", alpha: 0.5, properties: ["question^2"], fusionType: relativeScoreFusion}
+                )
+                {
+                  question
+                  _additional {
+                    score
+                    explainScore
+                  }
+                }
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+    }
+
+    #[test]
+    fn test_get_query_tenant_consistency_level() {
+        let query = GetQuery::new("JeopardyQuestion", vec!["question"])
+            .with_tenant("tenantA")
+            .with_consistency_level(ConsistencyLevel::QUORUM);
+
+        let expected = indoc! {r#"
+            {
+              Get {
+                JeopardyQuestion
+                (
+                  tenant: "tenantA"
+                  consistencyLevel: QUORUM
+                )
+                {
+                  question
+                }
+              }
+            }"#
+        };
+
+        assert_eq!(query.to_string(), expected);
+    }
+
+    #[test]
+    fn test_get_query_classes() {
+        let query = GetQuery::new(
+            "Article",
+            vec!["title", "hasAuthors { ... on Author { name } }"],
+        )
+        .with_where(
+            r#"{operator: And, operands: [
+                {path: ["inPublication", "Publication", "name"], operator: Equal, valueText: "NYT"},
+                {path: ["hasAuthors", "Author", "wroteArticles", "Article", "title"], operator: Like, valueText: "*"},
+                {path: ["wordCount"], operator: GreaterThan, valueInt: 100}
+            ]}"#,
+        );
+        assert_eq!(query.classes(), vec!["Article", "Author", "Publication"]);
+    }
 }
@@ -0,0 +1,180 @@
+use uuid::Uuid;
+
+use super::{FusionType, GroupBy, Hybrid, HybridSearch, NearMedia, SearchOperator};
+use crate::models::objects::{ConsistencyLevel, OrderBy, Sort};
+
+/// Conversion of a Rust value into a GraphQL value literal.
+///
+/// This is used to interpolate values into queries, such as with the `weaviate_query!` macro.
+/// Strings are quoted and escaped, so values from untrusted sources cannot change the structure
+/// of the query.
+///
+/// # Example
+/// ```
+/// use weaviate_community::models::query::ToGraphQL;
+///
+/// assert_eq!("say \"hi\"".to_graphql(), r#""say \"hi\"""#);
+/// assert_eq!(vec![1, 2].to_graphql(), "[1, 2]");
+/// assert_eq!(None::<u32>.to_graphql(), "null");
+/// ```
+pub trait ToGraphQL {
+    /// Render the value as a GraphQL value literal.
+    fn to_graphql(&self) -> String;
+}
+
+impl<T: ToGraphQL + ?Sized> ToGraphQL for &T {
+    fn to_graphql(&self) -> String {
+        (**self).to_graphql()
+    }
+}
+
+impl ToGraphQL for str {
+    fn to_graphql(&self) -> String {
+        serde_json::Value::from(self).to_string()
+    }
+}
+
+impl ToGraphQL for String {
+    fn to_graphql(&self) -> String {
+        self.as_str().to_graphql()
+    }
+}
+
+impl ToGraphQL for bool {
+    fn to_graphql(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! impl_to_graphql_int {
+    ($($ty:ty),*) => {
+        $(
+            impl ToGraphQL for $ty {
+                fn to_graphql(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_graphql_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl ToGraphQL for f32 {
+    fn to_graphql(&self) -> String {
+        f64::from(*self).to_graphql()
+    }
+}
+
+/// Non-finite numbers can not be represented in GraphQL, and are rendered as `null`.
+impl ToGraphQL for f64 {
+    fn to_graphql(&self) -> String {
+        serde_json::Value::from(*self).to_string()
+    }
+}
+
+impl ToGraphQL for Uuid {
+    fn to_graphql(&self) -> String {
+        format!("\"{self}\"")
+    }
+}
+
+impl<T: ToGraphQL> ToGraphQL for Option<T> {
+    fn to_graphql(&self) -> String {
+        match self {
+            Some(value) => value.to_graphql(),
+            None => "null".into(),
+        }
+    }
+}
+
+impl<T: ToGraphQL> ToGraphQL for [T] {
+    fn to_graphql(&self) -> String {
+        let items: Vec<String> = self.iter().map(ToGraphQL::to_graphql).collect();
+        format!("[{}]", items.join(", "))
+    }
+}
+
+impl<T: ToGraphQL, const N: usize> ToGraphQL for [T; N] {
+    fn to_graphql(&self) -> String {
+        self.as_slice().to_graphql()
+    }
+}
+
+impl<T: ToGraphQL> ToGraphQL for Vec<T> {
+    fn to_graphql(&self) -> String {
+        self.as_slice().to_graphql()
+    }
+}
+
+/// Enum values are rendered without quotes.
+impl ToGraphQL for ConsistencyLevel {
+    fn to_graphql(&self) -> String {
+        self.value().into()
+    }
+}
+
+/// Enum values are rendered without quotes.
+impl ToGraphQL for OrderBy {
+    fn to_graphql(&self) -> String {
+        self.value().into()
+    }
+}
+
+/// Enum values are rendered without quotes.
+impl ToGraphQL for FusionType {
+    fn to_graphql(&self) -> String {
+        self.value().into()
+    }
+}
+
+macro_rules! impl_to_graphql_display {
+    ($($ty:ty),*) => {
+        $(
+            impl ToGraphQL for $ty {
+                fn to_graphql(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_to_graphql_display!(
+    Sort,
+    Hybrid,
+    HybridSearch,
+    SearchOperator,
+    NearMedia,
+    GroupBy
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_graphql_scalars() {
+        assert_eq!("a\nb".to_graphql(), r#""a\nb""#);
+        assert_eq!(String::from("x").to_graphql(), r#""x""#);
+        assert_eq!(true.to_graphql(), "true");
+        assert_eq!((-3i64).to_graphql(), "-3");
+        assert_eq!(0.5f64.to_graphql(), "0.5");
+        assert_eq!(f64::NAN.to_graphql(), "null");
+        assert_eq!(
+            Uuid::nil().to_graphql(),
+            r#""00000000-0000-0000-0000-000000000000""#
+        );
+    }
+
+    #[test]
+    fn test_to_graphql_compound() {
+        assert_eq!(vec!["a", "b"].to_graphql(), r#"["a", "b"]"#);
+        assert_eq!(Some(&[1u8, 2][..]).to_graphql(), "[1, 2]");
+        assert_eq!(ConsistencyLevel::QUORUM.to_graphql(), "QUORUM");
+        assert_eq!(
+            vec![Sort::property("points").descending()].to_graphql(),
+            r#"[{path: ["points"], order: desc}]"#
+        );
+    }
+}
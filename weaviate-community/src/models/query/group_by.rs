@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+
+/// GroupBy struct to hold the `groupBy` argument of a Get query.
+///
+/// More information on grouping can be found [here](https://weaviate.io/developers/weaviate/search/similarity#group-results)
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupBy {
+    pub path: Vec<String>,
+    pub groups: u32,
+    pub objects_per_group: u32,
+}
+
+impl GroupBy {
+    /// Create a new GroupBy item.
+    ///
+    /// # Parameters
+    /// - path: the property path the results should be grouped by
+    /// - groups: the maximum number of groups to return
+    /// - objects_per_group: the maximum number of objects to return in each group
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::GroupBy;
+    ///
+    /// let group_by = GroupBy::new(vec!["document"], 3, 2);
+    /// ```
+    pub fn new(path: Vec<&str>, groups: u32, objects_per_group: u32) -> Self {
+        GroupBy {
+            path: path.iter().map(|item| item.to_string()).collect(),
+            groups,
+            objects_per_group,
+        }
+    }
+}
+
+impl std::fmt::Display for GroupBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{path: {}, groups: {}, objectsPerGroup: {}}}",
+            serde_json::Value::from(self.path.clone()),
+            self.groups,
+            self.objects_per_group
+        )
+    }
+}
+
+/// A single group returned by a Get query using `groupBy`.
+///
+/// You shouldn't need to ever create this struct - it is what the `group` field of the
+/// `_additional` properties is deserialized into. The `hits` are deserialized into `T`, which can
+/// contain an `_additional` field of type `AdditionalProperties` to read the `id` and `distance`
+/// of each hit.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Group<T> {
+    pub id: u64,
+    pub grouped_by: GroupedBy,
+    #[serde(default)]
+    pub min_distance: Option<f64>,
+    #[serde(default)]
+    pub max_distance: Option<f64>,
+    pub count: u64,
+    pub hits: Vec<T>,
+}
+
+/// The value and path that a group was created from.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GroupedBy {
+    pub value: String,
+    pub path: Vec<String>,
+}
+
+/// The result item of a Get query using `groupBy`, wrapping a single `Group`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupResult<T> {
+    #[serde(rename = "_additional")]
+    pub additional: GroupAdditional<T>,
+}
+
+/// The `_additional` properties of a `GroupResult`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupAdditional<T> {
+    pub group: Group<T>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_group_by_display() {
+        let group_by = GroupBy::new(vec!["document"], 3, 2);
+        assert_eq!(
+            group_by.to_string(),
+            "{path: [\"document\"], groups: 3, objectsPerGroup: 2}"
+        );
+    }
+
+    #[test]
+    fn test_group_result_deserialize() {
+        let res: GroupResult<serde_json::Value> = serde_json::from_value(serde_json::json!({
+            "_additional": {
+                "group": {
+                    "id": 0,
+                    "groupedBy": { "value": "doc-1", "path": ["document"] },
+                    "count": 2,
+                    "minDistance": 0.1,
+                    "maxDistance": 0.2,
+                    "hits": [{ "text": "a" }, { "text": "b" }]
+                }
+            }
+        }))
+        .unwrap();
+        let group = res.additional.group;
+        assert_eq!(group.grouped_by.value, "doc-1");
+        assert_eq!(group.count, 2);
+        assert_eq!(group.hits.len(), 2);
+        assert_eq!(group.max_distance, Some(0.2));
+    }
+}
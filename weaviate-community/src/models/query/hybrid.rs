@@ -0,0 +1,398 @@
+use serde::{Deserialize, Serialize};
+
+/// Strict definitions of the fusion algorithms used to combine the keyword and vector results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FusionType {
+    RankedFusion,
+    RelativeScoreFusion,
+}
+
+impl FusionType {
+    /// Get the GraphQL value for a given FusionType.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::query::FusionType;
+    ///
+    /// let val = FusionType::RelativeScoreFusion.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            FusionType::RankedFusion => "rankedFusion",
+            FusionType::RelativeScoreFusion => "relativeScoreFusion",
+        }
+    }
+}
+
+/// The operator used to combine the tokens of the keyword (bm25) part of a hybrid search.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchOperator {
+    /// All of the tokens must match.
+    And,
+    /// At least `minimum_match` of the tokens must match (defaults to 1).
+    Or { minimum_match: Option<u32> },
+}
+
+impl std::fmt::Display for SearchOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchOperator::And => write!(f, "{{operator: And}}"),
+            SearchOperator::Or { minimum_match } => {
+                write!(f, "{{operator: Or")?;
+                if let Some(minimum_match) = minimum_match {
+                    write!(f, ", minimumOrTokensMatch: {minimum_match}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// The vector search to use for the vector part of a hybrid search, instead of vectorizing the
+/// hybrid `query`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HybridSearch {
+    NearText {
+        concepts: Vec<String>,
+        distance: Option<f64>,
+    },
+    NearVector {
+        vector: Vec<f64>,
+        distance: Option<f64>,
+    },
+}
+
+impl std::fmt::Display for HybridSearch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let distance = match self {
+            HybridSearch::NearText { concepts, distance } => {
+                write!(
+                    f,
+                    "{{nearText: {{concepts: {}",
+                    serde_json::Value::from(concepts.clone())
+                )?;
+                distance
+            }
+            HybridSearch::NearVector { vector, distance } => {
+                write!(
+                    f,
+                    "{{nearVector: {{vector: {}",
+                    serde_json::Value::from(vector.clone())
+                )?;
+                distance
+            }
+        };
+        if let Some(distance) = distance {
+            write!(f, ", distance: {distance}")?;
+        }
+        write!(f, "}}}}")
+    }
+}
+
+/// Hybrid struct to hold the options of a `hybrid` search in a Get query.
+///
+/// The `hybrid` operator produces results based on a weighted combination of results from a
+/// keyword (bm25) search and a vector (near<media>) search.
+///
+/// More on hybrid search can be found [here](https://weaviate.io/developers/weaviate/search/hybrid)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hybrid {
+    pub query: String,
+    pub alpha: Option<f64>,
+    pub vector: Option<Vec<f64>>,
+    pub search: Option<HybridSearch>,
+    pub properties: Option<Vec<String>>,
+    pub fusion_type: Option<FusionType>,
+    pub max_vector_distance: Option<f64>,
+    pub target_vectors: Option<Vec<String>>,
+    pub bm25_search_operator: Option<SearchOperator>,
+}
+
+impl Hybrid {
+    /// Create a new Hybrid item for the given query.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Hybrid;
+    ///
+    /// let hybrid = Hybrid::new("food");
+    /// ```
+    pub fn new(query: impl Into<String>) -> Self {
+        Hybrid {
+            query: query.into(),
+            alpha: None,
+            vector: None,
+            search: None,
+            properties: None,
+            fusion_type: None,
+            max_vector_distance: None,
+            target_vectors: None,
+            bm25_search_operator: None,
+        }
+    }
+
+    /// Set the `alpha` weighting of the search, where `0` is a pure keyword search and `1` is a
+    /// pure vector search.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Hybrid;
+    ///
+    /// let hybrid = Hybrid::new("food").with_alpha(0.25);
+    /// ```
+    pub fn with_alpha(mut self, alpha: f64) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    /// Set the `vector` to use for the vector search, instead of vectorizing the query.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Hybrid;
+    ///
+    /// let hybrid = Hybrid::new("food").with_vector(vec![0.1, 0.2, 0.3]);
+    /// ```
+    pub fn with_vector(mut self, vector: Vec<f64>) -> Self {
+        self.vector = Some(vector);
+        self
+    }
+
+    /// Set a `nearText` or `nearVector` sub-search to use for the vector search.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{Hybrid, HybridSearch};
+    ///
+    /// let hybrid = Hybrid::new("food").with_search(HybridSearch::NearText {
+    ///     concepts: vec!["healthy food".into()],
+    ///     distance: Some(0.6),
+    /// });
+    /// ```
+    pub fn with_search(mut self, search: HybridSearch) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Set the `properties` to use for the keyword search.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Hybrid;
+    ///
+    /// let hybrid = Hybrid::new("food").with_properties(vec!["question", "answer"]);
+    /// ```
+    pub fn with_properties(mut self, properties: Vec<&str>) -> Self {
+        let properties = properties.iter().map(|prop| prop.to_string()).collect();
+        self.properties = Some(properties);
+        self
+    }
+
+    /// Add a property with a weight to boost it by in the keyword search, such as `question^2`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Hybrid;
+    ///
+    /// let hybrid = Hybrid::new("food")
+    ///     .with_property_weight("question", 2)
+    ///     .with_property_weight("answer", 1);
+    /// ```
+    pub fn with_property_weight(mut self, property: &str, weight: u32) -> Self {
+        self.properties
+            .get_or_insert_with(Vec::new)
+            .push(format!("{property}^{weight}"));
+        self
+    }
+
+    /// Set the `fusionType` used to combine the keyword and vector results.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{FusionType, Hybrid};
+    ///
+    /// let hybrid = Hybrid::new("food").with_fusion_type(FusionType::RelativeScoreFusion);
+    /// ```
+    pub fn with_fusion_type(mut self, fusion_type: FusionType) -> Self {
+        self.fusion_type = Some(fusion_type);
+        self
+    }
+
+    /// Set the `maxVectorDistance` of the objects returned by the vector search.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Hybrid;
+    ///
+    /// let hybrid = Hybrid::new("food").with_max_vector_distance(0.4);
+    /// ```
+    pub fn with_max_vector_distance(mut self, max_vector_distance: f64) -> Self {
+        self.max_vector_distance = Some(max_vector_distance);
+        self
+    }
+
+    /// Set the `targetVectors` to search, for classes with named vectors.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Hybrid;
+    ///
+    /// let hybrid = Hybrid::new("food").with_target_vectors(vec!["title_vector"]);
+    /// ```
+    pub fn with_target_vectors(mut self, target_vectors: Vec<&str>) -> Self {
+        let target_vectors = target_vectors.iter().map(|v| v.to_string()).collect();
+        self.target_vectors = Some(target_vectors);
+        self
+    }
+
+    /// Set the `bm25SearchOperator` used to combine the tokens of the keyword search.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{Hybrid, SearchOperator};
+    ///
+    /// let hybrid = Hybrid::new("healthy food")
+    ///     .with_bm25_search_operator(SearchOperator::Or { minimum_match: Some(2) });
+    /// ```
+    pub fn with_bm25_search_operator(mut self, operator: SearchOperator) -> Self {
+        self.bm25_search_operator = Some(operator);
+        self
+    }
+}
+
+impl std::fmt::Display for Hybrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{query: {}",
+            serde_json::Value::from(self.query.as_str())
+        )?;
+        if let Some(alpha) = &self.alpha {
+            write!(f, ", alpha: {alpha}")?;
+        }
+        if let Some(vector) = &self.vector {
+            write!(f, ", vector: {}", serde_json::Value::from(vector.clone()))?;
+        }
+        if let Some(search) = &self.search {
+            write!(f, ", searches: {search}")?;
+        }
+        if let Some(properties) = &self.properties {
+            write!(
+                f,
+                ", properties: {}",
+                serde_json::Value::from(properties.clone())
+            )?;
+        }
+        if let Some(fusion_type) = &self.fusion_type {
+            write!(f, ", fusionType: {}", fusion_type.value())?;
+        }
+        if let Some(max_vector_distance) = &self.max_vector_distance {
+            write!(f, ", maxVectorDistance: {max_vector_distance}")?;
+        }
+        if let Some(target_vectors) = &self.target_vectors {
+            write!(
+                f,
+                ", targetVectors: {}",
+                serde_json::Value::from(target_vectors.clone())
+            )?;
+        }
+        if let Some(operator) = &self.bm25_search_operator {
+            write!(f, ", bm25SearchOperator: {operator}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// A single component of the `explainScore` of a hybrid search result, describing the score of
+/// the object in one of the result sets (keyword or vector) that were fused.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ScoreComponent {
+    pub result_set: String,
+    pub original_score: f64,
+    pub normalized_score: f64,
+}
+
+/// Parse the `explainScore` additional property of a hybrid search result into its components.
+///
+/// Parts of the explanation that do not describe the score of a result set are skipped.
+///
+/// # Example
+/// ```
+/// use weaviate_community::models::query::parse_explain_score;
+///
+/// let explain = "Hybrid (Result Set keyword,bm25) Document 1: original score 2.2, normalized score: 0.7";
+/// let components = parse_explain_score(explain);
+/// assert_eq!(components[0].result_set, "keyword,bm25");
+/// ```
+pub fn parse_explain_score(explain_score: &str) -> Vec<ScoreComponent> {
+    explain_score
+        .split("Result Set ")
+        .skip(1)
+        .filter_map(|segment| {
+            let result_set = segment.split(')').next()?;
+            let number_after = |label: &str| -> Option<f64> {
+                let rest = segment.split(label).nth(1)?;
+                let rest = rest.trim_start_matches([':', ' ']);
+                let end = rest
+                    .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == 'e' || c == '-'))
+                    .unwrap_or(rest.len());
+                rest[..end].parse().ok()
+            };
+            Some(ScoreComponent {
+                result_set: result_set.trim().to_string(),
+                original_score: number_after("original score")?,
+                normalized_score: number_after("normalized score")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hybrid_display() {
+        let hybrid = Hybrid::new("food")
+            .with_alpha(0.5)
+            .with_property_weight("question", 2)
+            .with_fusion_type(FusionType::RelativeScoreFusion)
+            .with_max_vector_distance(0.4)
+            .with_target_vectors(vec!["title"])
+            .with_search(HybridSearch::NearVector {
+                vector: vec![0.5, 1.0],
+                distance: None,
+            })
+            .with_bm25_search_operator(SearchOperator::Or {
+                minimum_match: Some(2),
+            });
+        assert_eq!(
+            hybrid.to_string(),
+            "{query: \"food\", alpha: 0.5, searches: {nearVector: {vector: [0.5,1.0]}}, \
+             properties: [\"question^2\"], fusionType: relativeScoreFusion, \
+             maxVectorDistance: 0.4, targetVectors: [\"title\"], \
+             bm25SearchOperator: {operator: Or, minimumOrTokensMatch: 2}}"
+        );
+    }
+
+    #[test]
+    fn test_parse_explain_score() {
+        let explain = "\nHybrid (Result Set keyword,bm25) Document 1fd4c0ad-7a1a-4b4e-9d0c-2d1f5f6a0e8b: original score 2.2378, normalized score: 0.9 - \nHybrid (Result Set vector,hybridVector) Document 1fd4c0ad-7a1a-4b4e-9d0c-2d1f5f6a0e8b: original score 0.6287, normalized score: 0.1";
+        let components = parse_explain_score(explain);
+        assert_eq!(
+            components,
+            vec![
+                ScoreComponent {
+                    result_set: "keyword,bm25".into(),
+                    original_score: 2.2378,
+                    normalized_score: 0.9,
+                },
+                ScoreComponent {
+                    result_set: "vector,hybridVector".into(),
+                    original_score: 0.6287,
+                    normalized_score: 0.1,
+                },
+            ]
+        );
+    }
+}
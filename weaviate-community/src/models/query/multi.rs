@@ -0,0 +1,504 @@
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::json;
+
+use crate::error::{QueryError, WeaviateError};
+
+use super::{AggregateQuery, AggregateResult, GetQuery};
+
+/// MultiQuery struct to combine several Get and Aggregate queries into a single GraphQL request.
+///
+/// Each query is given an alias, which is used to retrieve its result from the
+/// `MultiQueryResponse`.
+#[derive(Debug, Default)]
+pub struct MultiQuery {
+    pub get: Vec<(String, GetQuery)>,
+    pub aggregate: Vec<(String, AggregateQuery)>,
+}
+
+impl MultiQuery {
+    /// Create a new, empty MultiQuery item.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::MultiQuery;
+    ///
+    /// let query_builder = MultiQuery::new();
+    /// ```
+    pub fn new() -> Self {
+        MultiQuery::default()
+    }
+
+    /// Add a Get query to the MultiQuery under the given alias.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{GetQuery, MultiQuery};
+    ///
+    /// let query_builder = MultiQuery::new()
+    ///     .with_get("questions", GetQuery::new("JeopardyQuestion", vec!["question"]));
+    /// ```
+    pub fn with_get(mut self, alias: impl Into<String>, query: GetQuery) -> Self {
+        self.get.push((alias.into(), query));
+        self
+    }
+
+    /// Add an Aggregate query to the MultiQuery under the given alias.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{AggregateQuery, MultiQuery};
+    ///
+    /// let query_builder = MultiQuery::new()
+    ///     .with_aggregate("articleCount", AggregateQuery::new("Article").with_meta_count());
+    /// ```
+    pub fn with_aggregate(mut self, alias: impl Into<String>, query: AggregateQuery) -> Self {
+        self.aggregate.push((alias.into(), query));
+        self
+    }
+
+    /// Check that there is at least one query, that every alias is a valid and unique GraphQL
+    /// name, and that each query is valid on its own.
+    fn validate(&self) -> Result<(), QueryError> {
+        if self.get.is_empty() && self.aggregate.is_empty() {
+            return Err(QueryError::MissingField(
+                "MultiQuery must contain at least one query".into(),
+            ));
+        }
+        let mut seen = std::collections::HashSet::new();
+        let aliases = self
+            .get
+            .iter()
+            .map(|(alias, _)| alias)
+            .chain(self.aggregate.iter().map(|(alias, _)| alias));
+        for alias in aliases {
+            if !is_valid_name(alias) || !seen.insert(alias) {
+                return Err(QueryError::InvalidAlias(alias.clone()));
+            }
+        }
+        for (_, query) in &self.get {
+            query.check()?;
+        }
+        Ok(())
+    }
+
+    /// Convert the `MultiQuery` into a `serde_json::Value` payload.
+    pub fn as_payload(&self) -> Result<serde_json::Value, QueryError> {
+        self.validate()?;
+        Ok(json!({ "query": self.to_string() }))
+    }
+
+    /// Find the lines of the rendered query that belong to each aliased query, used to attribute
+    /// errors without a `path` by their `locations`.
+    pub(crate) fn alias_lines(&self) -> Vec<AliasLines> {
+        let mut query = String::new();
+        // Writing to a String can not fail
+        self.render(&mut query).unwrap_or_default()
+    }
+
+    /// Write the query, returning the lines written for each aliased query.
+    ///
+    /// The lines are counted while each query is written rather than found in the output, as
+    /// filters such as `where` may contain lines that look like the start of another query.
+    fn render(&self, f: &mut dyn std::fmt::Write) -> Result<Vec<AliasLines>, std::fmt::Error> {
+        use std::fmt::Write;
+
+        let mut f = LineCounter { inner: f, lines: 0 };
+        let mut ranges = Vec::new();
+        writeln!(f, "{{")?;
+        if !self.get.is_empty() {
+            writeln!(f, "  Get {{")?;
+            for (alias, query) in &self.get {
+                let start = f.lines + 1;
+                query.write_class(&mut f, Some(alias))?;
+                ranges.push(AliasLines {
+                    operation: "Get".into(),
+                    alias: alias.clone(),
+                    lines: start..=f.lines,
+                });
+            }
+            writeln!(f, "  }}")?;
+        }
+        if !self.aggregate.is_empty() {
+            writeln!(f, "  Aggregate {{")?;
+            for (alias, query) in &self.aggregate {
+                let start = f.lines + 1;
+                query.write_class(&mut f, Some(alias))?;
+                ranges.push(AliasLines {
+                    operation: "Aggregate".into(),
+                    alias: alias.clone(),
+                    lines: start..=f.lines,
+                });
+            }
+            writeln!(f, "  }}")?;
+        }
+        write!(f, "}}")?;
+        Ok(ranges)
+    }
+}
+
+/// A writer counting the lines written to the inner writer.
+struct LineCounter<'a> {
+    inner: &'a mut dyn std::fmt::Write,
+    lines: u64,
+}
+
+impl std::fmt::Write for LineCounter<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.lines += s.matches('\n').count() as u64;
+        self.inner.write_str(s)
+    }
+}
+
+/// The lines of a rendered `MultiQuery` that belong to the query with the given operation
+/// (`Get` or `Aggregate`) and alias.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AliasLines {
+    operation: String,
+    alias: String,
+    lines: std::ops::RangeInclusive<u64>,
+}
+
+/// Check if a string is a valid GraphQL name, as required for aliases.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c == '_' || c.is_ascii_alphabetic() => {
+            chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+        }
+        _ => false,
+    }
+}
+
+impl std::fmt::Display for MultiQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(f).map(|_| ())
+    }
+}
+
+/// A single error returned in a GraphQL response.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GraphQLError {
+    pub message: String,
+    #[serde(default)]
+    pub path: Option<Vec<serde_json::Value>>,
+    #[serde(default)]
+    pub locations: Option<Vec<GraphQLLocation>>,
+}
+
+/// The location in the query of a `GraphQLError`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct GraphQLLocation {
+    pub line: u64,
+    pub column: u64,
+}
+
+impl GraphQLError {
+    /// Check if the error belongs to the query with the given operation (`Get` or `Aggregate`)
+    /// and alias, by its path or else by its locations in the query.
+    fn is_for(&self, operation: &str, alias: &str, lines: &[AliasLines]) -> bool {
+        match self.path_alias() {
+            Some((op, name)) => op == operation && name == alias,
+            None => self
+                .located_in(lines)
+                .any(|query| query.operation == operation && query.alias == alias),
+        }
+    }
+
+    /// Check if the error can not be attributed to a single query, because neither its path nor
+    /// its locations point into one of the queries.
+    fn is_general(&self, lines: &[AliasLines]) -> bool {
+        self.path_alias().is_none() && self.located_in(lines).next().is_none()
+    }
+
+    /// Get the operation and alias named by the path of the error, if the path is long enough to
+    /// name them, such as `["Get", "questions", 0]`.
+    fn path_alias(&self) -> Option<(&str, &str)> {
+        match self.path.as_deref()? {
+            [operation, alias, ..] => Some((operation.as_str()?, alias.as_str()?)),
+            _ => None,
+        }
+    }
+
+    /// Get the queries that contain a location of the error.
+    fn located_in<'a>(&'a self, lines: &'a [AliasLines]) -> impl Iterator<Item = &'a AliasLines> {
+        lines.iter().filter(move |query| {
+            self.locations
+                .iter()
+                .flatten()
+                .any(|location| query.lines.contains(&location.line))
+        })
+    }
+}
+
+/// The response to a `MultiQuery`.
+///
+/// The results of the individual queries are retrieved by their alias. Errors that the server
+/// attributes to a single query, by their path or by their location in the query, are only
+/// returned for that query, so a failure in one query does not prevent reading the results of the
+/// others.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MultiQueryResponse {
+    #[serde(default)]
+    pub data: Option<MultiQueryData>,
+    #[serde(default)]
+    pub errors: Option<Vec<GraphQLError>>,
+    #[serde(skip)]
+    pub(crate) lines: Vec<AliasLines>,
+}
+
+/// The `data` of a `MultiQueryResponse`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MultiQueryData {
+    #[serde(rename = "Get")]
+    #[serde(default)]
+    pub get: Option<serde_json::Map<String, serde_json::Value>>,
+    #[serde(rename = "Aggregate")]
+    #[serde(default)]
+    pub aggregate: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl MultiQueryResponse {
+    /// Get the errors that were attributed to the Get query with the given alias, including any
+    /// errors that can not be attributed to a single query.
+    pub fn get_errors(&self, alias: &str) -> Vec<&GraphQLError> {
+        self.errors_for("Get", alias)
+    }
+
+    /// Get the errors that were attributed to the Aggregate query with the given alias, including
+    /// any errors that can not be attributed to a single query.
+    pub fn aggregate_errors(&self, alias: &str) -> Vec<&GraphQLError> {
+        self.errors_for("Aggregate", alias)
+    }
+
+    /// Get the result of the Get query with the given alias.
+    ///
+    /// The result is the list of objects for the class, deserialized into `T`.
+    ///
+    /// # Errors
+    /// If the server returned errors for this query, or an error that is not attributed to any
+    /// query, a `WeaviateError::Other` containing the error messages is returned.
+    pub fn get<T: DeserializeOwned>(&self, alias: &str) -> Result<T, WeaviateError> {
+        let data = self.data.as_ref().and_then(|data| data.get.as_ref());
+        self.result("Get", alias, data)
+    }
+
+    /// Get the result of the Aggregate query with the given alias, deserialized into `T`.
+    ///
+    /// # Errors
+    /// If the server returned errors for this query, or an error that is not attributed to any
+    /// query, a `WeaviateError::Other` containing the error messages is returned.
+    pub fn aggregate<T: DeserializeOwned>(&self, alias: &str) -> Result<T, WeaviateError> {
+        let data = self.data.as_ref().and_then(|data| data.aggregate.as_ref());
+        self.result("Aggregate", alias, data)
+    }
+
+    /// Get the typed results of the Aggregate query with the given alias.
+    ///
+    /// # Errors
+    /// The same as `MultiQueryResponse::aggregate`.
+    pub fn aggregate_results(&self, alias: &str) -> Result<Vec<AggregateResult>, WeaviateError> {
+        self.aggregate(alias)
+    }
+
+    fn errors_for(&self, operation: &str, alias: &str) -> Vec<&GraphQLError> {
+        self.errors
+            .iter()
+            .flatten()
+            .filter(|err| err.is_for(operation, alias, &self.lines) || err.is_general(&self.lines))
+            .collect()
+    }
+
+    fn result<T: DeserializeOwned>(
+        &self,
+        operation: &str,
+        alias: &str,
+        data: Option<&serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<T, WeaviateError> {
+        let errors = self.errors_for(operation, alias);
+        if !errors.is_empty() {
+            let messages: Vec<&str> = errors.iter().map(|err| err.message.as_str()).collect();
+            return Err(WeaviateError::Other(messages.join("; ")));
+        }
+        let value = data
+            .and_then(|data| data.get(alias))
+            .ok_or_else(|| QueryError::MissingField(format!("{operation} result `{alias}`")))?;
+        Ok(serde_json::from_value(value.clone())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use super::*;
+
+    #[test]
+    fn test_multi_query() {
+        let query = MultiQuery::new()
+            .with_get(
+                "questions",
+                GetQuery::new("JeopardyQuestion", vec!["question"]).with_limit(2),
+            )
+            .with_aggregate("articles", AggregateQuery::new("Article").with_meta_count());
+
+        let expected = indoc! {"
+            {
+              Get {
+                questions: JeopardyQuestion
+                (
+                  limit: 2
+                )
+                {
+                  question
+                }
+              }
+              Aggregate {
+                articles: Article
+                {
+                  meta { count }
+                }
+              }
+            }"
+        };
+
+        assert_eq!(query.to_string(), expected);
+        assert!(query.as_payload().is_ok());
+    }
+
+    #[test]
+    fn test_multi_query_invalid_alias() {
+        let query = MultiQuery::new()
+            .with_get("a", GetQuery::new("JeopardyQuestion", vec!["question"]))
+            .with_get("a", GetQuery::new("JeopardyQuestion", vec!["answer"]));
+        assert!(matches!(
+            query.as_payload(),
+            Err(QueryError::InvalidAlias(alias)) if alias == "a"
+        ));
+
+        let query = MultiQuery::new().with_get("1a", GetQuery::new("Article", vec!["title"]));
+        assert!(query.as_payload().is_err());
+
+        assert!(MultiQuery::new().as_payload().is_err());
+    }
+
+    #[test]
+    fn test_multi_query_response_errors() {
+        let res: MultiQueryResponse = serde_json::from_value(serde_json::json!({
+            "data": {
+                "Get": {
+                    "ok": [{ "question": "q" }],
+                    "bad": null
+                }
+            },
+            "errors": [
+                {
+                    "message": "no such prop",
+                    "path": ["Get", "bad"],
+                    "locations": [{ "line": 10, "column": 5 }]
+                }
+            ]
+        }))
+        .unwrap();
+
+        let ok: Vec<serde_json::Value> = res.get("ok").unwrap();
+        assert_eq!(ok.len(), 1);
+        assert!(res.get::<serde_json::Value>("bad").is_err());
+        assert_eq!(res.get_errors("bad").len(), 1);
+        assert!(res.get_errors("ok").is_empty());
+    }
+
+    #[test]
+    fn test_multi_query_response_error_locations() {
+        let query = MultiQuery::new()
+            .with_get("ok", GetQuery::new("Article", vec!["title"]))
+            .with_get(
+                "bad",
+                GetQuery::new("Article", vec!["noSuchProperty"]).with_limit(2),
+            )
+            .with_aggregate("count", AggregateQuery::new("Article").with_meta_count());
+        let lines = query.alias_lines();
+        assert_eq!(
+            lines
+                .iter()
+                .map(|query| query.lines.clone())
+                .collect::<Vec<_>>(),
+            vec![3..=6, 7..=13, 16..=19]
+        );
+
+        // A validation error has no path, only its location in the `bad` query
+        let mut res: MultiQueryResponse = serde_json::from_value(serde_json::json!({
+            "errors": [
+                {
+                    "message": "Cannot query field \"noSuchProperty\" on type \"Article\".",
+                    "locations": [{ "line": 12, "column": 7 }]
+                }
+            ]
+        }))
+        .unwrap();
+        res.lines = lines;
+        assert_eq!(res.get_errors("bad").len(), 1);
+        assert!(res.get_errors("ok").is_empty());
+        assert!(res.aggregate_errors("count").is_empty());
+
+        // Errors with neither a path nor a location in a query apply to all queries
+        res.errors.as_mut().unwrap()[0].locations = None;
+        assert_eq!(res.get_errors("ok").len(), 1);
+        assert_eq!(res.aggregate_errors("count").len(), 1);
+    }
+
+    #[test]
+    fn test_multi_query_response_error_locations_where() {
+        let query = MultiQuery::new()
+            .with_get(
+                "filtered",
+                GetQuery::new("Article", vec!["title"]).with_where(indoc! {"
+                    {
+                      operator: And
+                      operands: [{
+                        path: [\"wordCount\"]
+                        operator: GreaterThan
+                        valueInt: 1000
+                      }]
+                    }"}),
+            )
+            .with_aggregate("count", AggregateQuery::new("Article").with_meta_count());
+        let lines = query.alias_lines();
+        let rendered = query.to_string();
+        let path_line = rendered
+            .lines()
+            .position(|line| line.starts_with("    path: "))
+            .unwrap() as u64
+            + 1;
+        assert_eq!(
+            lines
+                .iter()
+                .map(|query| query.alias.as_str())
+                .collect::<Vec<_>>(),
+            vec!["filtered", "count"]
+        );
+        assert!(lines[0].lines.contains(&path_line));
+
+        let mut res: MultiQueryResponse = serde_json::from_value(serde_json::json!({
+            "errors": [{
+                "message": "invalid filter",
+                "locations": [{ "line": path_line, "column": 5 }]
+            }]
+        }))
+        .unwrap();
+        res.lines = lines;
+        assert_eq!(res.get_errors("filtered").len(), 1);
+        assert!(res.aggregate_errors("count").is_empty());
+    }
+
+    #[test]
+    fn test_multi_query_response_short_path() {
+        let res: MultiQueryResponse = serde_json::from_value(serde_json::json!({
+            "data": {"Get": {"questions": []}},
+            "errors": [{ "message": "get failed", "path": ["Get"] }]
+        }))
+        .unwrap();
+        assert_eq!(res.get_errors("questions").len(), 1);
+        assert_eq!(res.aggregate_errors("count").len(), 1);
+        assert!(res.get::<Vec<serde_json::Value>>("questions").is_err());
+    }
+}
@@ -0,0 +1,311 @@
+use uuid::Uuid;
+
+use crate::error::QueryError;
+
+/// Move struct to hold the `moveTo` or `moveAwayFrom` options of a `nearText` search.
+///
+/// The results are moved towards (or away from) the given concepts and objects, by the given
+/// `force` between 0 and 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Move {
+    pub force: f64,
+    pub concepts: Vec<String>,
+    pub objects: Vec<Uuid>,
+}
+
+impl Move {
+    /// Create a new Move item with the given force.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Move;
+    ///
+    /// let move_to = Move::new(0.85).with_concepts(vec!["haute couture"]);
+    /// ```
+    pub fn new(force: f64) -> Self {
+        Move {
+            force,
+            concepts: Vec::new(),
+            objects: Vec::new(),
+        }
+    }
+
+    /// Set the concepts to move the results by.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Move;
+    ///
+    /// let move_to = Move::new(0.85).with_concepts(vec!["haute couture"]);
+    /// ```
+    pub fn with_concepts(mut self, concepts: Vec<&str>) -> Self {
+        self.concepts = concepts.iter().map(|concept| concept.to_string()).collect();
+        self
+    }
+
+    /// Set the objects to move the results by.
+    ///
+    /// # Example
+    /// ```
+    /// use uuid::Uuid;
+    /// use weaviate_community::models::query::Move;
+    ///
+    /// let uuid = Uuid::parse_str("ee22d1b8-3b95-4e94-96d5-9a2b60fbd303").unwrap();
+    /// let move_away = Move::new(0.5).with_objects(vec![uuid]);
+    /// ```
+    pub fn with_objects(mut self, objects: Vec<Uuid>) -> Self {
+        self.objects = objects;
+        self
+    }
+}
+
+impl std::fmt::Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        if !self.concepts.is_empty() {
+            write!(
+                f,
+                "concepts: {}, ",
+                serde_json::Value::from(self.concepts.clone())
+            )?;
+        }
+        if !self.objects.is_empty() {
+            let objects: Vec<String> = self
+                .objects
+                .iter()
+                .map(|id| format!("{{id: \"{id}\"}}"))
+                .collect();
+            write!(f, "objects: [{}], ", objects.join(", "))?;
+        }
+        write!(f, "force: {}}}", self.force)
+    }
+}
+
+/// NearText struct to hold the options of a `nearText` search.
+///
+/// Only one of `certainty` or `distance` can be set.
+///
+/// More on nearText can be found [here](https://weaviate.io/developers/weaviate/api/graphql/search-operators#neartext)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearText {
+    pub concepts: Vec<String>,
+    pub certainty: Option<f64>,
+    pub distance: Option<f64>,
+    pub move_to: Option<Move>,
+    pub move_away_from: Option<Move>,
+}
+
+impl NearText {
+    /// Create a new NearText item for the given concepts.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::NearText;
+    ///
+    /// let near_text = NearText::new(vec!["fashion"]);
+    /// ```
+    pub fn new(concepts: Vec<&str>) -> Self {
+        NearText {
+            concepts: concepts.iter().map(|concept| concept.to_string()).collect(),
+            certainty: None,
+            distance: None,
+            move_to: None,
+            move_away_from: None,
+        }
+    }
+
+    /// Set the minimum `certainty` of the results.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::NearText;
+    ///
+    /// let near_text = NearText::new(vec!["fashion"]).with_certainty(0.7);
+    /// ```
+    pub fn with_certainty(mut self, certainty: f64) -> Self {
+        self.certainty = Some(certainty);
+        self
+    }
+
+    /// Set the maximum `distance` of the results.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::NearText;
+    ///
+    /// let near_text = NearText::new(vec!["fashion"]).with_distance(0.3);
+    /// ```
+    pub fn with_distance(mut self, distance: f64) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Move the results towards other concepts or objects.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{Move, NearText};
+    ///
+    /// let near_text = NearText::new(vec!["fashion"])
+    ///     .with_move_to(Move::new(0.85).with_concepts(vec!["haute couture"]));
+    /// ```
+    pub fn with_move_to(mut self, move_to: Move) -> Self {
+        self.move_to = Some(move_to);
+        self
+    }
+
+    /// Move the results away from other concepts or objects.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{Move, NearText};
+    ///
+    /// let near_text = NearText::new(vec!["fashion"])
+    ///     .with_move_away_from(Move::new(0.45).with_concepts(vec!["finance"]));
+    /// ```
+    pub fn with_move_away_from(mut self, move_away_from: Move) -> Self {
+        self.move_away_from = Some(move_away_from);
+        self
+    }
+
+    pub(crate) fn check(&self) -> Result<(), QueryError> {
+        check_limits(self.certainty, self.distance)
+    }
+}
+
+impl std::fmt::Display for NearText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{concepts: {}",
+            serde_json::Value::from(self.concepts.clone())
+        )?;
+        write_limits(f, self.certainty, self.distance)?;
+        if let Some(move_to) = &self.move_to {
+            write!(f, ", moveTo: {move_to}")?;
+        }
+        if let Some(move_away_from) = &self.move_away_from {
+            write!(f, ", moveAwayFrom: {move_away_from}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// NearVector struct to hold the options of a `nearVector` search.
+///
+/// Only one of `certainty` or `distance` can be set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearVector {
+    pub vector: Vec<f64>,
+    pub certainty: Option<f64>,
+    pub distance: Option<f64>,
+}
+
+impl NearVector {
+    /// Create a new NearVector item for the given vector.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::NearVector;
+    ///
+    /// let near_vector = NearVector::new(vec![-0.36840257, 0.13973749, -0.28994447]);
+    /// ```
+    pub fn new(vector: Vec<f64>) -> Self {
+        NearVector {
+            vector,
+            certainty: None,
+            distance: None,
+        }
+    }
+
+    /// Set the minimum `certainty` of the results.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::NearVector;
+    ///
+    /// let near_vector = NearVector::new(vec![0.1, 0.2]).with_certainty(0.7);
+    /// ```
+    pub fn with_certainty(mut self, certainty: f64) -> Self {
+        self.certainty = Some(certainty);
+        self
+    }
+
+    /// Set the maximum `distance` of the results.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::NearVector;
+    ///
+    /// let near_vector = NearVector::new(vec![0.1, 0.2]).with_distance(0.3);
+    /// ```
+    pub fn with_distance(mut self, distance: f64) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    pub(crate) fn check(&self) -> Result<(), QueryError> {
+        check_limits(self.certainty, self.distance)
+    }
+}
+
+impl std::fmt::Display for NearVector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{vector: {}",
+            serde_json::Value::from(self.vector.clone())
+        )?;
+        write_limits(f, self.certainty, self.distance)?;
+        write!(f, "}}")
+    }
+}
+
+fn check_limits(certainty: Option<f64>, distance: Option<f64>) -> Result<(), QueryError> {
+    if certainty.is_some() && distance.is_some() {
+        return Err(QueryError::InvalidCombination(&["certainty", "distance"]));
+    }
+    Ok(())
+}
+
+fn write_limits(
+    f: &mut std::fmt::Formatter<'_>,
+    certainty: Option<f64>,
+    distance: Option<f64>,
+) -> std::fmt::Result {
+    if let Some(certainty) = certainty {
+        write!(f, ", certainty: {certainty}")?;
+    }
+    if let Some(distance) = distance {
+        write!(f, ", distance: {distance}")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_near_text_display() {
+        let near_text = NearText::new(vec!["fashion"])
+            .with_distance(0.3)
+            .with_move_to(Move::new(0.85).with_concepts(vec!["haute couture"]))
+            .with_move_away_from(Move::new(0.45).with_objects(vec![Uuid::nil()]));
+        assert_eq!(
+            near_text.to_string(),
+            r#"{concepts: ["fashion"], distance: 0.3, moveTo: {concepts: ["haute couture"], force: 0.85}, moveAwayFrom: {objects: [{id: "00000000-0000-0000-0000-000000000000"}], force: 0.45}}"#
+        );
+        assert!(near_text.check().is_ok());
+        assert!(near_text.with_certainty(0.7).check().is_err());
+    }
+
+    #[test]
+    fn test_near_vector_display() {
+        let near_vector = NearVector::new(vec![0.5, -1.0]).with_certainty(0.7);
+        assert_eq!(
+            near_vector.to_string(),
+            "{vector: [0.5,-1.0], certainty: 0.7}"
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use std::io::Read;
+use std::path::Path;
+
+use base64::Engine;
+
+use super::ToGraphQL;
+
+/// Strict definitions of the media types that can be searched with a `near<media>` filter.
+///
+/// Each media type requires a module that can vectorize it, such as `multi2vec-bind` or
+/// `img2vec-neural` for images.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaType {
+    Image,
+    Audio,
+    Video,
+    Thermal,
+    Depth,
+    IMU,
+}
+
+impl MediaType {
+    /// Get the name of the GraphQL filter for the MediaType, such as `nearImage`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::query::MediaType;
+    ///
+    /// let val = MediaType::Image.operator();
+    /// ```
+    pub fn operator(&self) -> &str {
+        match self {
+            MediaType::Image => "nearImage",
+            MediaType::Audio => "nearAudio",
+            MediaType::Video => "nearVideo",
+            MediaType::Thermal => "nearThermal",
+            MediaType::Depth => "nearDepth",
+            MediaType::IMU => "nearIMU",
+        }
+    }
+
+    /// Get the name of the field holding the encoded media in the filter, such as `image`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::query::MediaType;
+    ///
+    /// let val = MediaType::Image.field();
+    /// ```
+    pub fn field(&self) -> &str {
+        match self {
+            MediaType::Image => "image",
+            MediaType::Audio => "audio",
+            MediaType::Video => "video",
+            MediaType::Thermal => "thermal",
+            MediaType::Depth => "depth",
+            MediaType::IMU => "imu",
+        }
+    }
+}
+
+/// NearMedia struct to hold a `near<media>` filter, such as `nearImage` or `nearAudio`.
+///
+/// The media is stored base64 encoded, as required by Weaviate.
+///
+/// More information on media search can be found [here](https://weaviate.io/developers/weaviate/search/image)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearMedia {
+    pub media_type: MediaType,
+    pub data: String,
+    pub certainty: Option<f64>,
+    pub distance: Option<f64>,
+}
+
+impl NearMedia {
+    /// Create a new NearMedia item from the raw bytes of the media, which are base64 encoded.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{MediaType, NearMedia};
+    ///
+    /// let near_image = NearMedia::new(MediaType::Image, b"raw image bytes");
+    /// ```
+    pub fn new(media_type: MediaType, bytes: impl AsRef<[u8]>) -> Self {
+        let data = base64::engine::general_purpose::STANDARD.encode(bytes);
+        Self::from_base64(media_type, data)
+    }
+
+    /// Create a new NearMedia item from media that is already base64 encoded.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{MediaType, NearMedia};
+    ///
+    /// let near_audio = NearMedia::from_base64(MediaType::Audio, "UklGRiQAAABXQVZF");
+    /// ```
+    pub fn from_base64(media_type: MediaType, data: impl Into<String>) -> Self {
+        NearMedia {
+            media_type,
+            data: data.into(),
+            certainty: None,
+            distance: None,
+        }
+    }
+
+    /// Create a new NearMedia item by reading and encoding the file at the given path.
+    ///
+    /// # Errors
+    /// If the file cannot be read.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use weaviate_community::models::query::{MediaType, NearMedia};
+    ///
+    /// let near_image = NearMedia::from_file(MediaType::Image, "images/cat.jpg").unwrap();
+    /// ```
+    pub fn from_file(media_type: MediaType, path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::new(media_type, std::fs::read(path)?))
+    }
+
+    /// Create a new NearMedia item by reading and encoding everything from the reader.
+    ///
+    /// # Errors
+    /// If the reader returns an error.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{MediaType, NearMedia};
+    ///
+    /// let reader = std::io::Cursor::new(vec![0u8, 1, 2]);
+    /// let near_video = NearMedia::from_reader(MediaType::Video, reader).unwrap();
+    /// ```
+    pub fn from_reader(media_type: MediaType, mut reader: impl Read) -> std::io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Ok(Self::new(media_type, bytes))
+    }
+
+    /// Set the minimum `certainty` of the results.
+    ///
+    /// Cannot be used with `distance`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{MediaType, NearMedia};
+    ///
+    /// let near_image = NearMedia::new(MediaType::Image, b"raw image bytes")
+    ///     .with_certainty(0.8);
+    /// ```
+    pub fn with_certainty(mut self, certainty: f64) -> Self {
+        self.certainty = Some(certainty);
+        self
+    }
+
+    /// Set the maximum `distance` of the results.
+    ///
+    /// Cannot be used with `certainty`.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::{MediaType, NearMedia};
+    ///
+    /// let near_image = NearMedia::new(MediaType::Image, b"raw image bytes")
+    ///     .with_distance(0.2);
+    /// ```
+    pub fn with_distance(mut self, distance: f64) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+}
+
+impl std::fmt::Display for NearMedia {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{{{}: {}",
+            self.media_type.field(),
+            self.data.to_graphql()
+        )?;
+        if let Some(certainty) = &self.certainty {
+            write!(f, " certainty: {certainty}")?;
+        }
+        if let Some(distance) = &self.distance {
+            write!(f, " distance: {distance}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_near_media_display() {
+        let near_image = NearMedia::new(MediaType::Image, b"hello").with_certainty(0.7);
+        assert_eq!(
+            near_image.to_string(),
+            "{image: \"aGVsbG8=\" certainty: 0.7}"
+        );
+
+        let near_imu = NearMedia::from_reader(MediaType::IMU, &b"hello"[..])
+            .unwrap()
+            .with_distance(0.25);
+        assert_eq!(near_imu.to_string(), "{imu: \"aGVsbG8=\" distance: 0.25}");
+    }
+
+    #[test]
+    fn test_near_media_display_escapes_data() {
+        let near_image = NearMedia::from_base64(MediaType::Image, "a\"} b\\");
+        assert_eq!(near_image.to_string(), r#"{image: "a\"} b\\"}"#);
+    }
+}
@@ -4,6 +4,8 @@ use serde_json::json;
 #[derive(Debug)]
 pub struct RawQuery {
     pub query: String,
+    pub variables: Option<serde_json::Map<String, serde_json::Value>>,
+    pub operation_name: Option<String>,
 }
 
 impl RawQuery {
@@ -28,11 +30,128 @@ impl RawQuery {
     pub fn new(query: &str) -> Self {
         RawQuery {
             query: query.into(),
+            variables: None,
+            operation_name: None,
         }
     }
 
+    /// Set the value of a GraphQL variable used in the query.
+    ///
+    /// Variables are sent separately from the query, so the values do not need to be escaped or
+    /// interpolated into the query string.
+    ///
+    /// # Parameters
+    /// - name: the name of the variable, without the leading `$`
+    /// - value: the value of the variable
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::RawQuery;
+    ///
+    /// let query = RawQuery::new(
+    ///     "query Questions($limit: Int) { Get { JeopardyQuestion(limit: $limit) { question } } }"
+    /// )
+    /// .with_variable("limit", 5);
+    /// ```
+    pub fn with_variable(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.variables
+            .get_or_insert_with(serde_json::Map::new)
+            .insert(name.into(), value.into());
+        self
+    }
+
+    /// Set the name of the operation to execute.
+    ///
+    /// This is required when the query document contains more than one named operation.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::RawQuery;
+    ///
+    /// let query = RawQuery::new(
+    ///     "query Questions { Get { JeopardyQuestion { question } } }"
+    /// )
+    /// .with_operation_name("Questions");
+    /// ```
+    pub fn with_operation_name(mut self, operation_name: impl Into<String>) -> Self {
+        self.operation_name = Some(operation_name.into());
+        self
+    }
+
     /// Convert the `RawQuery` into a `serde_json::Value` payload.
     pub fn as_payload(&self) -> serde_json::Value {
-        json!({ "query": self.query })
+        let mut payload = json!({ "query": self.query });
+        if let Some(variables) = &self.variables {
+            payload["variables"] = variables.clone().into();
+        }
+        if let Some(operation_name) = &self.operation_name {
+            payload["operationName"] = operation_name.clone().into();
+        }
+        payload
+    }
+}
+
+/// Create a `RawQuery` from a `.graphql` file, which is embedded into the binary at build time.
+///
+/// The path is relative to the root of the crate that invokes the macro (the directory
+/// containing its `Cargo.toml`). An optional operation name can be provided to select a named
+/// operation from a file containing several of them.
+///
+/// # Example
+/// ```ignore
+/// use weaviate_community::include_query;
+///
+/// let query = include_query!("queries/jeopardy.graphql", "Questions")
+///     .with_variable("limit", 5);
+/// ```
+#[macro_export]
+macro_rules! include_query {
+    ($path:literal) => {
+        $crate::models::query::RawQuery::new(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/",
+            $path
+        )))
+    };
+    ($path:literal, $operation_name:literal) => {
+        $crate::include_query!($path).with_operation_name($operation_name)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_query_payload() {
+        let query = RawQuery::new("{ Get { JeopardyQuestion { question } } }");
+        assert_eq!(
+            query.as_payload(),
+            json!({ "query": "{ Get { JeopardyQuestion { question } } }" })
+        );
+
+        let query = RawQuery::new("query Q($limit: Int) { Get { A(limit: $limit) { b } } }")
+            .with_variable("limit", 5)
+            .with_variable("unused", vec!["a", "b"])
+            .with_operation_name("Q");
+        assert_eq!(
+            query.as_payload(),
+            json!({
+                "query": "query Q($limit: Int) { Get { A(limit: $limit) { b } } }",
+                "variables": { "limit": 5, "unused": ["a", "b"] },
+                "operationName": "Q"
+            })
+        );
+    }
+
+    #[test]
+    fn test_include_query() {
+        let query = crate::include_query!("tests/queries/jeopardy.graphql", "QuestionCount");
+        assert!(query.query.contains("query Questions($limit: Int)"));
+        assert_eq!(query.operation_name.as_deref(), Some("QuestionCount"));
     }
 }
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+/// Rerank struct to hold the `rerank` additional property of a Get query.
+///
+/// Reranking requires a reranker module (for example `reranker-cohere` or
+/// `reranker-transformers`) to be enabled for the class being queried.
+///
+/// More information on reranking can be found [here](https://weaviate.io/developers/weaviate/search/rerank)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rerank {
+    pub property: String,
+    pub query: Option<String>,
+}
+
+impl Rerank {
+    /// Create a new Rerank item for the given property.
+    ///
+    /// When no `query` is set, Weaviate reranks using the query of the search operator
+    /// (for example the `nearText` or `hybrid` query).
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Rerank;
+    ///
+    /// let rerank = Rerank::new("answer");
+    /// ```
+    pub fn new(property: impl Into<String>) -> Self {
+        Rerank {
+            property: property.into(),
+            query: None,
+        }
+    }
+
+    /// Set the `query` the results should be reranked against.
+    ///
+    /// # Example
+    /// ```
+    /// use weaviate_community::models::query::Rerank;
+    ///
+    /// let rerank = Rerank::new("answer").with_query("floating");
+    /// ```
+    pub fn with_query(mut self, query: impl Into<String>) -> Self {
+        self.query = Some(query.into());
+        self
+    }
+}
+
+impl std::fmt::Display for Rerank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "rerank(property: {}",
+            serde_json::Value::from(self.property.as_str())
+        )?;
+        if let Some(query) = &self.query {
+            write!(f, " query: {}", serde_json::Value::from(query.as_str()))?;
+        }
+        write!(f, ") {{ score }}")
+    }
+}
+
+/// The score assigned to a result by the reranker module.
+///
+/// You shouldn't need to ever create this struct - it is what the `rerank` field of the
+/// `_additional` properties is deserialized into.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RerankScore {
+    #[serde(default)]
+    pub score: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rerank_display() {
+        let rerank = Rerank::new("answer");
+        assert_eq!(rerank.to_string(), "rerank(property: \"answer\") { score }");
+
+        let rerank = Rerank::new("answer").with_query("floating \"ice\"");
+        assert_eq!(
+            rerank.to_string(),
+            "rerank(property: \"answer\" query: \"floating \\\"ice\\\"\") { score }"
+        );
+    }
+}
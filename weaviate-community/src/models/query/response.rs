@@ -1,7 +1,10 @@
 use serde::Deserialize;
+use uuid::Uuid;
 
 use crate::error::WeaviateError;
 
+use super::{parse_explain_score, RerankScore, ScoreComponent};
+
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum MaybeError<T> {
@@ -20,9 +23,24 @@ impl<T> MaybeError<T> {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Errors {
+    #[serde(deserialize_with = "non_null")]
     pub errors: serde_json::Value,
 }
 
+/// Deserialize a value, failing if it is `null`.
+///
+/// Responses may contain `"errors": null` alongside the data, which must not be treated as an
+/// error.
+fn non_null<'de, D>(deserializer: D) -> Result<serde_json::Value, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Null => Err(serde::de::Error::custom("errors is null")),
+        value => Ok(value),
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct GraphQLGetResponse<T> {
     pub data: GetData<T>,
@@ -55,3 +73,79 @@ pub struct ExploreData<T> {
     #[serde(rename = "Explore")]
     pub explore: T,
 }
+
+/// The `_additional` properties that can be returned for each object in a Get query.
+///
+/// Only the fields that were requested in the query will be populated. This can be used as the
+/// type of an `_additional` field in your own result structs, for example:
+/// ```
+/// use serde::Deserialize;
+/// use weaviate_community::models::query::AdditionalProperties;
+///
+/// #[derive(Deserialize)]
+/// struct JeopardyQuestion {
+///     question: String,
+///     #[serde(rename = "_additional")]
+///     additional: AdditionalProperties,
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdditionalProperties {
+    #[serde(default)]
+    pub id: Option<Uuid>,
+    #[serde(default)]
+    pub vector: Option<Vec<f64>>,
+    #[serde(default)]
+    pub distance: Option<f64>,
+    #[serde(default)]
+    pub certainty: Option<f64>,
+    #[serde(default)]
+    pub score: Option<String>,
+    #[serde(default)]
+    pub explain_score: Option<String>,
+    #[serde(default)]
+    pub rerank: Option<Vec<RerankScore>>,
+}
+
+impl AdditionalProperties {
+    /// Get the score assigned by the reranker module, if `rerank` was requested.
+    pub fn rerank_score(&self) -> Option<f64> {
+        self.rerank.as_ref()?.first()?.score
+    }
+
+    /// Get the components of the `explainScore` of a hybrid search result, if it was requested.
+    pub fn explain_score_components(&self) -> Option<Vec<ScoreComponent>> {
+        self.explain_score.as_deref().map(parse_explain_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_additional_properties_rerank() {
+        let additional: AdditionalProperties = serde_json::from_value(serde_json::json!({
+            "id": "7e9b9ffe-e645-302d-9d94-517670623b35",
+            "score": "0.5",
+            "rerank": [{ "score": 0.9123 }]
+        }))
+        .unwrap();
+        assert_eq!(additional.rerank_score(), Some(0.9123));
+        assert_eq!(additional.score.as_deref(), Some("0.5"));
+        assert!(additional.distance.is_none());
+    }
+
+    #[test]
+    fn test_additional_properties_explain_score() {
+        let additional: AdditionalProperties = serde_json::from_value(serde_json::json!({
+            "score": "0.8",
+            "explainScore": "\nHybrid (Result Set keyword,bm25) Document 7e9b9ffe-e645-302d-9d94-517670623b35: original score 1.5, normalized score: 0.8"
+        }))
+        .unwrap();
+        let components = additional.explain_score_components().unwrap();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].original_score, 1.5);
+    }
+}
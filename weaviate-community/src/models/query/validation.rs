@@ -0,0 +1,582 @@
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+use crate::error::QueryError;
+use crate::models::schema::{Class, Classes, Property};
+
+/// A problem found when validating a query against the schema.
+///
+/// These are reported by `GetQuery::validate` and `AggregateQuery::validate` inside of a
+/// `QueryError::Validation`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationProblem {
+    /// The class does not exist in the schema.
+    UnknownClass(String),
+    /// The property does not exist in the class.
+    UnknownProperty { class: String, property: String },
+    /// A nested selection or path was used on a property that is not a cross-reference.
+    NotAReference { class: String, property: String },
+    /// A reference fragment or path targets a class that the property does not point to.
+    InvalidReferenceTarget {
+        class: String,
+        property: String,
+        target: String,
+    },
+    /// The property is used in a `where` filter but has `indexFilterable` disabled.
+    NotFilterable { class: String, property: String },
+    /// The property is used to sort but has `indexFilterable` disabled.
+    NotSortable { class: String, property: String },
+}
+
+impl std::fmt::Display for ValidationProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationProblem::UnknownClass(class) => {
+                write!(f, "class `{class}` does not exist in the schema")
+            }
+            ValidationProblem::UnknownProperty { class, property } => {
+                write!(f, "property `{property}` does not exist in class `{class}`")
+            }
+            ValidationProblem::NotAReference { class, property } => {
+                write!(
+                    f,
+                    "property `{property}` of class `{class}` is not a reference"
+                )
+            }
+            ValidationProblem::InvalidReferenceTarget {
+                class,
+                property,
+                target,
+            } => write!(
+                f,
+                "property `{property}` of class `{class}` does not reference class `{target}`"
+            ),
+            ValidationProblem::NotFilterable { class, property } => write!(
+                f,
+                "property `{property}` of class `{class}` is not indexed for filtering"
+            ),
+            ValidationProblem::NotSortable { class, property } => write!(
+                f,
+                "property `{property}` of class `{class}` is not indexed for sorting"
+            ),
+        }
+    }
+}
+
+/// How a property path is used in the query, which determines the index it requires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PathUsage {
+    Filter,
+    Sort,
+    Group,
+}
+
+/// The tokens of a GraphQL snippet that are relevant for validation.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Name(String),
+    Str(String),
+    Spread,
+    LBrace,
+    RBrace,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Colon,
+    Other,
+}
+
+fn tokenize(input: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() || c == ',' => continue,
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            ':' => Token::Colon,
+            '.' => {
+                while chars.next_if_eq(&'.').is_some() {}
+                Token::Spread
+            }
+            '"' => {
+                let mut value = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => value.extend(chars.next()),
+                        c => value.push(c),
+                    }
+                }
+                Token::Str(value)
+            }
+            c if c == '_' || c.is_ascii_alphanumeric() => {
+                let mut name = c.to_string();
+                while let Some(c) = chars.next_if(|c| *c == '_' || c.is_ascii_alphanumeric()) {
+                    name.push(c);
+                }
+                Token::Name(name)
+            }
+            _ => Token::Other,
+        };
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// Extract all of the `path` arguments from a GraphQL snippet, such as a `where` filter.
+pub(crate) fn extract_paths(input: &str) -> Vec<Vec<String>> {
+    let mut paths = Vec::new();
+    let mut tokens = tokenize(input).into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        if token != Token::Name("path".into()) || tokens.next_if_eq(&Token::Colon).is_none() {
+            continue;
+        }
+        match tokens.next() {
+            Some(Token::Str(value)) => paths.push(vec![value]),
+            Some(Token::LBracket) => {
+                let mut path = Vec::new();
+                while let Some(Token::Str(value)) = tokens.next_if(|t| matches!(t, Token::Str(_))) {
+                    path.push(value);
+                }
+                paths.push(path);
+            }
+            _ => {}
+        }
+    }
+    paths
+}
+
+/// Extract all of the string literals from a GraphQL snippet, such as a `groupBy` filter.
+fn extract_strings(input: &str) -> Vec<String> {
+    tokenize(input)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Str(value) => Some(value),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Check if a property is a cross-reference, which is the case when its data types are classes.
+fn is_reference(property: &Property) -> bool {
+    property
+        .data_type
+        .iter()
+        .any(|data_type| data_type.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+/// Check if a property is an object, which can contain nested properties.
+fn is_object(property: &Property) -> bool {
+    property
+        .data_type
+        .iter()
+        .any(|data_type| data_type.starts_with("object"))
+}
+
+/// Validates the parts of a query against a schema, collecting the problems found.
+pub(crate) struct Validator<'a> {
+    schema: &'a Classes,
+    problems: Vec<ValidationProblem>,
+}
+
+impl<'a> Validator<'a> {
+    pub(crate) fn new(schema: &'a Classes) -> Self {
+        Validator {
+            schema,
+            problems: Vec::new(),
+        }
+    }
+
+    /// Finish the validation, returning all of the problems found as a `QueryError`.
+    pub(crate) fn finish(self) -> Result<(), QueryError> {
+        if self.problems.is_empty() {
+            Ok(())
+        } else {
+            Err(QueryError::Validation(self.problems))
+        }
+    }
+
+    /// Look up a class, recording a problem if it does not exist.
+    pub(crate) fn class(&mut self, name: &str) -> Option<&'a Class> {
+        let class = self.schema.classes.iter().find(|class| class.class == name);
+        if class.is_none() {
+            self.problems
+                .push(ValidationProblem::UnknownClass(name.to_string()));
+        }
+        class
+    }
+
+    /// Look up a property of a class, recording a problem if it does not exist.
+    pub(crate) fn property(&mut self, class: &'a Class, name: &str) -> Option<&'a Property> {
+        let property = class
+            .properties
+            .iter()
+            .flat_map(|properties| properties.0.iter())
+            .find(|property| property.name == name);
+        if property.is_none() {
+            self.problems.push(ValidationProblem::UnknownProperty {
+                class: class.class.clone(),
+                property: name.to_string(),
+            });
+        }
+        property
+    }
+
+    /// Look up the target class of a reference property, recording a problem if the property is
+    /// not a reference to it, or if it does not exist.
+    fn reference_target(
+        &mut self,
+        class: &'a Class,
+        property: &'a Property,
+        target: &str,
+    ) -> Option<&'a Class> {
+        if !is_reference(property) {
+            self.problems.push(ValidationProblem::NotAReference {
+                class: class.class.clone(),
+                property: property.name.clone(),
+            });
+            return None;
+        }
+        if !property
+            .data_type
+            .iter()
+            .any(|data_type| data_type == target)
+        {
+            self.problems
+                .push(ValidationProblem::InvalidReferenceTarget {
+                    class: class.class.clone(),
+                    property: property.name.clone(),
+                    target: target.to_string(),
+                });
+            return None;
+        }
+        self.class(target)
+    }
+
+    /// Validate a property path, such as one used in a `where` filter.
+    ///
+    /// Paths through cross-references alternate between the reference property and the target
+    /// class, for example `["inPublication", "Publication", "name"]`.
+    pub(crate) fn path(&mut self, class: &'a Class, path: &[String], usage: PathUsage) {
+        let mut class = class;
+        let mut segments = path.iter();
+        while let Some(name) = segments.next() {
+            if name == "id" || name.starts_with('_') {
+                return;
+            }
+            let Some(property) = self.property(class, name) else {
+                return;
+            };
+            if property.index_filterable == Some(false) {
+                let (class, property) = (class.class.clone(), property.name.clone());
+                self.problems.extend(match usage {
+                    PathUsage::Filter => Some(ValidationProblem::NotFilterable { class, property }),
+                    PathUsage::Sort => Some(ValidationProblem::NotSortable { class, property }),
+                    PathUsage::Group => None,
+                });
+            }
+            let Some(target) = segments.next() else {
+                return;
+            };
+            match self.reference_target(class, property, target) {
+                Some(target) => class = target,
+                None => return,
+            }
+        }
+    }
+
+    /// Validate all of the `path` arguments in a GraphQL snippet.
+    pub(crate) fn paths_in(&mut self, class: &'a Class, input: &str, usage: PathUsage) {
+        for path in extract_paths(input) {
+            self.path(class, &path, usage);
+        }
+    }
+
+    /// Validate a `groupBy` filter given as a GraphQL list of strings.
+    pub(crate) fn group_by_in(&mut self, class: &'a Class, input: &str) {
+        self.path(class, &extract_strings(input), PathUsage::Group);
+    }
+
+    /// Validate the selection of properties in the body of a Get query.
+    pub(crate) fn selection(&mut self, class: &'a Class, input: &str) {
+        let mut tokens = tokenize(input).into_iter().peekable();
+        self.selection_set(Some(class), &mut tokens);
+    }
+
+    /// Validate the top level fields in the body of an Aggregate query, without validating the
+    /// aggregations selected for each of them.
+    pub(crate) fn aggregate_fields(&mut self, class: &'a Class, input: &str) {
+        let mut tokens = tokenize(input).into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            match token {
+                Token::Name(name) => {
+                    if name != "meta" && name != "groupedBy" && !name.starts_with('_') {
+                        self.property(class, &name);
+                    }
+                    skip_arguments(&mut tokens);
+                    if tokens.next_if_eq(&Token::LBrace).is_some() {
+                        skip_block(&mut tokens);
+                    }
+                }
+                Token::LBrace => skip_block(&mut tokens),
+                _ => {}
+            }
+        }
+    }
+
+    /// Validate a selection set until its closing brace, or the end of the input.
+    ///
+    /// When the class is `None` (because it does not exist) the selection is consumed without
+    /// reporting further problems.
+    fn selection_set(&mut self, class: Option<&'a Class>, tokens: &mut Peekable<IntoIter<Token>>) {
+        while let Some(token) = tokens.next() {
+            match token {
+                Token::RBrace => return,
+                Token::Name(name) => {
+                    skip_arguments(tokens);
+                    let has_block = tokens.next_if_eq(&Token::LBrace).is_some();
+                    let property = match class {
+                        Some(class) if !name.starts_with('_') => self
+                            .property(class, &name)
+                            .map(|property| (class, property)),
+                        _ => None,
+                    };
+                    match (property, has_block) {
+                        (Some((class, property)), true) if is_reference(property) => {
+                            self.reference_selection(class, property, tokens)
+                        }
+                        (Some((class, property)), true) if !is_object(property) => {
+                            self.problems.push(ValidationProblem::NotAReference {
+                                class: class.class.clone(),
+                                property: property.name.clone(),
+                            });
+                            skip_block(tokens);
+                        }
+                        (_, true) => skip_block(tokens),
+                        (_, false) => {}
+                    }
+                }
+                Token::LBrace => skip_block(tokens),
+                _ => {}
+            }
+        }
+    }
+
+    /// Validate the selection of a reference property, which contains `... on Class { }`
+    /// fragments for the referenced classes.
+    fn reference_selection(
+        &mut self,
+        class: &'a Class,
+        property: &'a Property,
+        tokens: &mut Peekable<IntoIter<Token>>,
+    ) {
+        while let Some(token) = tokens.next() {
+            match token {
+                Token::RBrace => return,
+                Token::Spread => {
+                    tokens.next_if_eq(&Token::Name("on".into()));
+                    let target = match tokens.next() {
+                        Some(Token::Name(target)) => target,
+                        _ => continue,
+                    };
+                    let target = self.reference_target(class, property, &target);
+                    if tokens.next_if_eq(&Token::LBrace).is_some() {
+                        self.selection_set(target, tokens);
+                    }
+                }
+                Token::Name(_) => {
+                    skip_arguments(tokens);
+                    if tokens.next_if_eq(&Token::LBrace).is_some() {
+                        skip_block(tokens);
+                    }
+                }
+                Token::LBrace => skip_block(tokens),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Skip the arguments of a field, if there are any.
+fn skip_arguments(tokens: &mut Peekable<IntoIter<Token>>) {
+    if tokens.next_if_eq(&Token::LParen).is_none() {
+        return;
+    }
+    let mut depth = 1;
+    for token in tokens.by_ref() {
+        match token {
+            Token::LParen => depth += 1,
+            Token::RParen => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            return;
+        }
+    }
+}
+
+/// Skip the remainder of a block, after its opening brace has been consumed.
+fn skip_block(tokens: &mut Peekable<IntoIter<Token>>) {
+    let mut depth = 1;
+    for token in tokens.by_ref() {
+        match token {
+            Token::LBrace => depth += 1,
+            Token::RBrace => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::objects::Sort;
+    use crate::models::query::{AggregateField, AggregateQuery, GetQuery, GroupBy};
+    use crate::models::schema::Properties;
+
+    fn test_schema() -> Classes {
+        Classes::new(vec![
+            Class::builder("JeopardyQuestion")
+                .with_properties(Properties::new(vec![
+                    Property::builder("question", vec!["text"]).build(),
+                    Property::builder("answer", vec!["text"])
+                        .with_index_filterable(false)
+                        .build(),
+                    Property::builder("points", vec!["int"]).build(),
+                    Property::builder("hasCategory", vec!["JeopardyCategory"]).build(),
+                ]))
+                .build(),
+            Class::builder("JeopardyCategory")
+                .with_properties(Properties::new(vec![Property::builder(
+                    "title",
+                    vec!["text"],
+                )
+                .build()]))
+                .build(),
+        ])
+    }
+
+    fn expect_problems(res: Result<(), QueryError>) -> Vec<ValidationProblem> {
+        match res {
+            Err(QueryError::Validation(problems)) => problems,
+            other => panic!("expected validation problems, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_get_query_ok() {
+        let query = GetQuery::new(
+            "JeopardyQuestion",
+            vec![
+                "question",
+                "hasCategory { ... on JeopardyCategory { title _additional { id } } }",
+            ],
+        )
+        .with_where("{path: [\"hasCategory\", \"JeopardyCategory\", \"title\"], operator: Equal, valueText: \"a\"}")
+        .with_sort(vec![Sort::property("points").descending()])
+        .with_group_by(GroupBy::new(vec!["answer"], 2, 2))
+        .with_additional(vec!["id"]);
+        assert!(query.validate(&test_schema()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_get_query_problems() {
+        let schema = test_schema();
+        let query = GetQuery::new(
+            "JeopardyQuestion",
+            vec![
+                "questoin",
+                "points { value }",
+                "hasCategory { ... on JeopardyQuestion { question } ... on Category { title } }",
+            ],
+        )
+        .with_where("{path: [\"answer\"], operator: Equal, valueText: \"a\"}")
+        .with_sort(vec![Sort::property("answer")]);
+        let problems = expect_problems(query.validate(&schema));
+        assert_eq!(
+            problems,
+            vec![
+                ValidationProblem::UnknownProperty {
+                    class: "JeopardyQuestion".into(),
+                    property: "questoin".into(),
+                },
+                ValidationProblem::NotAReference {
+                    class: "JeopardyQuestion".into(),
+                    property: "points".into(),
+                },
+                ValidationProblem::InvalidReferenceTarget {
+                    class: "JeopardyQuestion".into(),
+                    property: "hasCategory".into(),
+                    target: "JeopardyQuestion".into(),
+                },
+                ValidationProblem::InvalidReferenceTarget {
+                    class: "JeopardyQuestion".into(),
+                    property: "hasCategory".into(),
+                    target: "Category".into(),
+                },
+                ValidationProblem::NotFilterable {
+                    class: "JeopardyQuestion".into(),
+                    property: "answer".into(),
+                },
+                ValidationProblem::NotSortable {
+                    class: "JeopardyQuestion".into(),
+                    property: "answer".into(),
+                },
+            ]
+        );
+
+        let query = GetQuery::new("Question", vec!["question"]);
+        assert_eq!(
+            expect_problems(query.validate(&schema)),
+            vec![ValidationProblem::UnknownClass("Question".into())]
+        );
+    }
+
+    #[test]
+    fn test_validate_aggregate_query() {
+        let schema = test_schema();
+        let query = AggregateQuery::new("JeopardyQuestion")
+            .with_meta_count()
+            .with_group_by(vec!["hasCategory"])
+            .with_aggregations(vec![AggregateField::int("points")])
+            .with_fields(vec!["question { count }"]);
+        assert!(query.validate(&schema).is_ok());
+
+        let query = AggregateQuery::new("JeopardyQuestion")
+            .with_aggregations(vec![AggregateField::int("score")])
+            .with_fields(vec!["title { count }"]);
+        assert_eq!(expect_problems(query.validate(&schema)).len(), 2);
+    }
+
+    #[test]
+    fn test_extract_paths() {
+        let paths = extract_paths(
+            r#"{operator: And, operands: [
+                {path: ["wordCount"], operator: GreaterThan, valueInt: 1000},
+                {path: ["inPublication", "Publication", "name"], operator: Equal, valueText: "a"},
+                {path: "title", operator: Like, valueText: "path: [\"x\"]"}
+            ]}"#,
+        );
+        assert_eq!(
+            paths,
+            vec![
+                vec!["wordCount".to_string()],
+                vec![
+                    "inPublication".to_string(),
+                    "Publication".to_string(),
+                    "name".to_string()
+                ],
+                vec!["title".to_string()],
+            ]
+        );
+    }
+}
@@ -2278,11 +2278,47 @@ impl Shard {
     }
 }
 
-/// Strict definitions of ShardStatus.
+/// The status of a shard.
 ///
-/// Weaviate supports READONLY and READY shard status.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// Only READONLY and READY can be set with `Schema::update_class_shard`. Statuses that are not
+/// known by this crate are deserialized into `ShardStatus::Unknown`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ShardStatus {
     READONLY,
     READY,
+    INDEXING,
+    LOADING,
+    Unknown(String),
+}
+
+impl ShardStatus {
+    /// Retrieve the string value associated to the ShardStatus enum types.
+    ///
+    /// # Example
+    /// ```rust
+    /// use weaviate_community::models::schema::ShardStatus;
+    ///
+    /// let ready = ShardStatus::READY.value();
+    /// ```
+    pub fn value(&self) -> &str {
+        match self {
+            ShardStatus::READONLY => "READONLY",
+            ShardStatus::READY => "READY",
+            ShardStatus::INDEXING => "INDEXING",
+            ShardStatus::LOADING => "LOADING",
+            ShardStatus::Unknown(status) => status,
+        }
+    }
+
+    fn from_value(status: String) -> ShardStatus {
+        match status.as_str() {
+            "READONLY" => ShardStatus::READONLY,
+            "READY" => ShardStatus::READY,
+            "INDEXING" => ShardStatus::INDEXING,
+            "LOADING" => ShardStatus::LOADING,
+            _ => ShardStatus::Unknown(status),
+        }
+    }
 }
+
+impl_status_serde!(ShardStatus);
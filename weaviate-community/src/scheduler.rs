@@ -0,0 +1,496 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+use crate::error::WeaviateError;
+use crate::models::backups::{
+    BackupBackends, BackupConfig, BackupCreateRequest, BackupRetention, BackupSchedule,
+    BackupStatus, BackupStatusResponse, BackupWaitOptions,
+};
+use crate::{civil_from_days, days_from_civil, WeaviateClient};
+
+/// The callback type for the result of each scheduled backup, see `BackupScheduler::with_report`.
+type BackupReportCallback = Arc<dyn Fn(&BackupRunReport) + Send + Sync>;
+
+/// Creates backups on a schedule, and finds old backups according to a retention policy.
+///
+/// Each backup gets a timestamped id of the form `{prefix}-{YYYYMMDD}-{HHMMSS}`, using the time
+/// in UTC at which it was started. The scheduler waits for each backup to complete, and once it
+/// succeeds, finds the expired backups created by the scheduler by listing the backups on the
+/// backend. The result of each run is reported to the callback set with `with_report`.
+///
+/// Weaviate has no endpoint for deleting a completed backup, so expired backups are not removed
+/// by the scheduler. Their ids are reported in `BackupRunReport::expired`, for the caller to
+/// delete them from the storage of the backend.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use weaviate_community::{BackupScheduler, WeaviateClient};
+/// use weaviate_community::models::backups::{BackupBackends, BackupRetention, BackupSchedule};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = WeaviateClient::builder("http://localhost:8080").build()?;
+///     let scheduler = BackupScheduler::new(
+///         &client,
+///         BackupBackends::S3,
+///         BackupSchedule::Daily { hour: 2, minute: 0 },
+///     )
+///     .with_id_prefix("nightly")
+///     .with_retention(BackupRetention::new().with_keep_last(7))
+///     .with_report(|report| {
+///         match &report.result {
+///             Ok(_) => println!("backup {} succeeded", report.backup_id),
+///             Err(err) => eprintln!("backup {} failed: {err}", report.backup_id),
+///         }
+///         for backup_id in &report.expired {
+///             println!("backup {backup_id} expired and can be deleted from the bucket");
+///         }
+///     });
+///
+///     let handle = scheduler.start();
+///     tokio::time::sleep(Duration::from_secs(7 * 24 * 60 * 60)).await;
+///     handle.stop();
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BackupScheduler {
+    client: WeaviateClient,
+    backend: BackupBackends,
+    schedule: BackupSchedule,
+    id_prefix: String,
+    include: Option<Vec<String>>,
+    exclude: Option<Vec<String>>,
+    config: Option<BackupConfig>,
+    wait_options: BackupWaitOptions,
+    retention: BackupRetention,
+    on_report: Option<BackupReportCallback>,
+}
+
+/// The result of a single backup created by a `BackupScheduler`.
+#[derive(Debug)]
+pub struct BackupRunReport {
+    /// The id of the backup.
+    pub backup_id: String,
+    /// The final status of the backup, or the error that stopped it.
+    pub result: Result<BackupStatusResponse, WeaviateError>,
+    /// The ids of the backups that expired according to the retention policy, which are not
+    /// removed by the scheduler.
+    pub expired: Vec<String>,
+    /// The error from listing the backups to find the expired backups.
+    pub retention_error: Option<WeaviateError>,
+}
+
+/// A handle to a running `BackupScheduler`, returned by `BackupScheduler::start`.
+///
+/// Dropping the handle does not stop the scheduler.
+#[derive(Debug)]
+pub struct BackupSchedulerHandle {
+    handle: JoinHandle<()>,
+}
+
+impl BackupSchedulerHandle {
+    /// Stop the scheduler. A backup that is in progress is not cancelled on the server.
+    pub fn stop(self) {
+        self.handle.abort();
+    }
+
+    /// Check if the scheduler is still running.
+    pub fn is_running(&self) -> bool {
+        !self.handle.is_finished()
+    }
+}
+
+impl BackupScheduler {
+    /// Create a new BackupScheduler creating backups on the given backend.
+    ///
+    /// The scheduler uses a clone of the client, and the prefix of the backup ids is `backup`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use weaviate_community::{BackupScheduler, WeaviateClient};
+    /// use weaviate_community::models::backups::{BackupBackends, BackupSchedule};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = WeaviateClient::builder("http://localhost:8080").build()?;
+    /// let scheduler = BackupScheduler::new(
+    ///     &client,
+    ///     BackupBackends::FILESYSTEM,
+    ///     BackupSchedule::Every(Duration::from_secs(60 * 60)),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        client: &WeaviateClient,
+        backend: BackupBackends,
+        schedule: BackupSchedule,
+    ) -> BackupScheduler {
+        BackupScheduler {
+            client: client.clone(),
+            backend,
+            schedule,
+            id_prefix: "backup".into(),
+            include: None,
+            exclude: None,
+            config: None,
+            wait_options: BackupWaitOptions::default(),
+            retention: BackupRetention::default(),
+            on_report: None,
+        }
+    }
+
+    /// Set the prefix of the ids of the backups.
+    ///
+    /// Backup ids may only contain lowercase letters, numbers, `-` and `_`. The retention policy
+    /// only applies to the backups whose id has this prefix.
+    pub fn with_id_prefix(mut self, id_prefix: &str) -> BackupScheduler {
+        self.id_prefix = id_prefix.into();
+        self
+    }
+
+    /// Set the classes to include in each backup.
+    pub fn with_include(mut self, include: Vec<&str>) -> BackupScheduler {
+        self.include = Some(include.iter().map(|field| field.to_string()).collect());
+        self
+    }
+
+    /// Set the classes to exclude from each backup.
+    pub fn with_exclude(mut self, exclude: Vec<&str>) -> BackupScheduler {
+        self.exclude = Some(exclude.iter().map(|field| field.to_string()).collect());
+        self
+    }
+
+    /// Set the configuration of each backup.
+    pub fn with_config(mut self, config: BackupConfig) -> BackupScheduler {
+        self.config = Some(config);
+        self
+    }
+
+    /// Set the options for waiting for each backup to complete.
+    pub fn with_wait_options(mut self, wait_options: BackupWaitOptions) -> BackupScheduler {
+        self.wait_options = wait_options;
+        self
+    }
+
+    /// Set which backups are reported as expired after each successful backup.
+    pub fn with_retention(mut self, retention: BackupRetention) -> BackupScheduler {
+        self.retention = retention;
+        self
+    }
+
+    /// Set a callback that is called with the result of each backup.
+    pub fn with_report<F>(mut self, on_report: F) -> BackupScheduler
+    where
+        F: Fn(&BackupRunReport) + Send + Sync + 'static,
+    {
+        self.on_report = Some(Arc::new(on_report));
+        self
+    }
+
+    /// Start the scheduler in a new tokio task.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn start(self) -> BackupSchedulerHandle {
+        let handle = tokio::spawn(async move {
+            let mut next = Instant::now();
+            loop {
+                let delay = match self.schedule.until_daily(since_epoch()) {
+                    Some(delay) => delay,
+                    None => {
+                        if let BackupSchedule::Every(interval) = self.schedule {
+                            next = (next + interval).max(Instant::now());
+                        }
+                        next.saturating_duration_since(Instant::now())
+                    }
+                };
+                tokio::time::sleep(delay).await;
+                let report = self.run_once().await;
+                if let Some(on_report) = &self.on_report {
+                    on_report(&report);
+                }
+            }
+        });
+        BackupSchedulerHandle { handle }
+    }
+
+    /// Create a single backup now, wait for it to complete, and apply the retention policy.
+    ///
+    /// The report is returned rather than passed to the callback of the scheduler.
+    pub async fn run_once(&self) -> BackupRunReport {
+        let now = since_epoch();
+        let backup_id = format!("{}-{}", self.id_prefix, format_timestamp(now));
+        let mut report = BackupRunReport {
+            result: self.backup(&backup_id).await,
+            backup_id,
+            expired: Vec::new(),
+            retention_error: None,
+        };
+        if report.result.is_ok() && !self.retention.is_keep_all() {
+            self.apply_retention(now, &mut report).await;
+        }
+        report
+    }
+
+    async fn backup(&self, backup_id: &str) -> Result<BackupStatusResponse, WeaviateError> {
+        let request = BackupCreateRequest {
+            id: backup_id.into(),
+            include: self.include.clone(),
+            exclude: self.exclude.clone(),
+            config: self.config.clone(),
+        };
+        let backups = self.client.backups();
+        backups.create(self.backend.clone(), &request, None).await?;
+        backups
+            .wait_for_completion(self.backend.clone(), backup_id, false, &self.wait_options)
+            .await
+    }
+
+    async fn apply_retention(&self, now: Duration, report: &mut BackupRunReport) {
+        let listed = match self.client.backups().list(self.backend.clone()).await {
+            Ok(listed) => listed,
+            Err(err) => {
+                report.retention_error = Some(err);
+                return;
+            }
+        };
+        let prefix = format!("{}-", self.id_prefix);
+        let mut created: Vec<(Duration, String)> = listed
+            .into_iter()
+            .filter(|backup| backup.status == BackupStatus::SUCCESS)
+            .filter_map(|backup| {
+                let created = parse_timestamp(backup.id.strip_prefix(&prefix)?)?;
+                Some((created, backup.id))
+            })
+            .collect();
+        // The listing may not include the backup that just completed yet, but it still counts
+        // towards the backups to keep
+        if !created.iter().any(|(_, id)| *id == report.backup_id) {
+            created.push((now, report.backup_id.clone()));
+        }
+        report.expired = self.retention.expired(created, now, &report.backup_id);
+    }
+}
+
+impl std::fmt::Debug for BackupScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BackupScheduler")
+            .field("backend", &self.backend)
+            .field("schedule", &self.schedule)
+            .field("id_prefix", &self.id_prefix)
+            .field("include", &self.include)
+            .field("exclude", &self.exclude)
+            .field("config", &self.config)
+            .field("wait_options", &self.wait_options)
+            .field("retention", &self.retention)
+            .field("on_report", &self.on_report.is_some())
+            .finish()
+    }
+}
+
+fn since_epoch() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// Format a time since the epoch as `YYYYMMDD-HHMMSS` in UTC.
+fn format_timestamp(time: Duration) -> String {
+    let secs = time.as_secs();
+    let (year, month, day) = civil_from_days(secs / 86400);
+    let secs = secs % 86400;
+    format!(
+        "{year:04}{month:02}{day:02}-{:02}{:02}{:02}",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// Parse a `YYYYMMDD-HHMMSS` timestamp in UTC into the time since the epoch.
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+    let (date, time) = timestamp.split_once('-')?;
+    if date.len() != 8
+        || time.len() != 6
+        || !(date.to_owned() + time).bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let number = |s: &str| s.parse::<u64>().ok();
+    let (year, month, day) = (
+        number(&date[..4])?,
+        number(&date[4..6])?,
+        number(&date[6..])?,
+    );
+    let (hour, minute, second) = (
+        number(&time[..2])?,
+        number(&time[2..4])?,
+        number(&time[4..])?,
+    );
+    if year < 1970 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+    Some(Duration::from_secs(
+        days * 86400 + hour * 3600 + minute * 60 + second,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    async fn get_test_harness() -> (mockito::ServerGuard, WeaviateClient) {
+        let mock_server = mockito::Server::new_async().await;
+        let mut host = "http://".to_string();
+        host.push_str(&mock_server.host_with_port());
+        let client = WeaviateClient::builder(&host).build().unwrap();
+        (mock_server, client)
+    }
+
+    fn test_status(status: &str) -> String {
+        serde_json::json!({
+            "backend": "filesystem",
+            "id": "nightly",
+            "path": null,
+            "status": status,
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let time = Duration::from_secs(1_709_260_245);
+        assert_eq!(format_timestamp(time), "20240301-023045");
+        assert_eq!(parse_timestamp("20240301-023045"), Some(time));
+        assert_eq!(format_timestamp(Duration::ZERO), "19700101-000000");
+        assert_eq!(parse_timestamp("19700101-000000"), Some(Duration::ZERO));
+        assert!(parse_timestamp("20241301-000000").is_none());
+        assert!(parse_timestamp("2024-03-01").is_none());
+        assert!(parse_timestamp("+2024030-023045").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_run_once_with_retention() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let backup_path =
+            mockito::Matcher::Regex(r"^/v1/backups/filesystem/nightly-\d{8}-\d{6}$".into());
+        let create = mock_server
+            .mock("POST", "/v1/backups/filesystem")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "backend": "filesystem",
+                    "classes": [],
+                    "id": "nightly",
+                    "path": "",
+                    "status": "STARTED"
+                })
+                .to_string(),
+            )
+            .create();
+        let status = mock_server
+            .mock("GET", backup_path)
+            .with_status(200)
+            .with_body(test_status("SUCCESS"))
+            .create();
+        let list = mock_server
+            .mock("GET", "/v1/backups/filesystem")
+            .with_status(200)
+            .with_body(
+                serde_json::json!([
+                    {"id": "nightly-20200102-000000", "status": "SUCCESS"},
+                    {"id": "nightly-20200101-000000", "status": "SUCCESS"},
+                    {"id": "nightly-20200103-000000", "status": "FAILED"},
+                    {"id": "manual", "status": "SUCCESS"}
+                ])
+                .to_string(),
+            )
+            .create();
+        let delete = mock_server
+            .mock("DELETE", mockito::Matcher::Any)
+            .expect(0)
+            .create();
+
+        let report = BackupScheduler::new(
+            &client,
+            BackupBackends::FILESYSTEM,
+            BackupSchedule::Every(Duration::from_secs(60)),
+        )
+        .with_id_prefix("nightly")
+        .with_retention(BackupRetention::new().with_keep_last(2))
+        .run_once()
+        .await;
+
+        create.assert();
+        status.assert();
+        list.assert();
+        delete.assert();
+        assert!(report.backup_id.starts_with("nightly-"));
+        assert_eq!(report.result.unwrap().status, BackupStatus::SUCCESS);
+        assert_eq!(report.expired, vec!["nightly-20200101-000000"]);
+        assert!(report.retention_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_run_once_failed_skips_retention() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let create = mock_server
+            .mock("POST", "/v1/backups/filesystem")
+            .with_status(422)
+            .create();
+        let list = mock_server
+            .mock("GET", "/v1/backups/filesystem")
+            .expect(0)
+            .create();
+
+        let report = BackupScheduler::new(
+            &client,
+            BackupBackends::FILESYSTEM,
+            BackupSchedule::Every(Duration::from_secs(60)),
+        )
+        .with_retention(BackupRetention::new().with_keep_last(1))
+        .run_once()
+        .await;
+
+        create.assert();
+        list.assert();
+        assert!(report.result.is_err());
+        assert!(report.expired.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_start_reports() {
+        let (mut mock_server, client) = get_test_harness().await;
+        let _create = mock_server
+            .mock("POST", "/v1/backups/filesystem")
+            .with_status(500)
+            .create();
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let handle = {
+            let reports = reports.clone();
+            BackupScheduler::new(
+                &client,
+                BackupBackends::FILESYSTEM,
+                BackupSchedule::Every(Duration::from_millis(10)),
+            )
+            .with_report(move |report| reports.lock().unwrap().push(report.backup_id.clone()))
+            .start()
+        };
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(handle.is_running());
+        handle.stop();
+        assert!(!reports.lock().unwrap().is_empty());
+    }
+}